@@ -1,18 +1,45 @@
 //! Value conversions
 use crate::markers::BitMasks;
+use crate::{Resolution, Rounding};
 
 pub fn convert_temp_from_register(msb: u8, lsb: u8, mask: u16) -> f32 {
-    // msb is stored as two's complement
-    let msb = f32::from(msb as i8);
-    let decimal = f32::from((lsb & mask as u8) >> 5) * 0.125;
-    msb + decimal
+    // The register is stored as two's complement with 8 fractional bits
+    f32::from(convert_temp_raw_from_register(msb, lsb, mask)) / 256.0
 }
 
-pub fn convert_temp_to_register(temp: f32, mask: u16) -> (u8, u8) {
-    let int = (temp / 0.125) as i16 as u16;
-    let binary = int << 5;
-    let msb = (binary >> 8) as u8;
-    let lsb = (binary & mask) as u8;
+pub fn convert_temp_raw_from_register(msb: u8, lsb: u8, mask: u16) -> i16 {
+    (u16::from_be_bytes([msb, lsb]) & mask) as i16
+}
+
+pub fn convert_temp_to_raw(temp: f32, mask: u16, rounding: Rounding) -> i64 {
+    // Round to the resolution given by the mask
+    let step = i64::from((!mask).wrapping_add(1));
+    let steps = temp * 256.0 / step as f32;
+    let steps = match rounding {
+        Rounding::Truncate => steps as i64,
+        Rounding::Nearest if steps < 0.0 => (steps - 0.5) as i64,
+        Rounding::Nearest => (steps + 0.5) as i64,
+    };
+    steps * step
+}
+
+pub fn convert_resolution_from_mask(mask: u16) -> f32 {
+    // The LSB of the mask corresponds to the temperature step
+    // and the register contains 8 fractional bits.
+    f32::from((!mask).wrapping_add(1)) / 256.0
+}
+
+pub fn convert_resolution_to_mask(resolution: Resolution) -> u16 {
+    match resolution {
+        Resolution::Bits9 => BitMasks::RESOLUTION_9BIT,
+        Resolution::Bits10 => BitMasks::RESOLUTION_10BIT,
+        Resolution::Bits11 => BitMasks::RESOLUTION_11BIT,
+        Resolution::Bits12 => BitMasks::RESOLUTION_12BIT,
+    }
+}
+
+pub fn convert_temp_raw_to_register(raw: i16, mask: u16) -> (u8, u8) {
+    let [msb, lsb] = (raw as u16 & mask).to_be_bytes();
     (msb, lsb)
 }
 
@@ -26,10 +53,81 @@ pub fn convert_sample_rate_to_register(period: u16) -> u8 {
     (period / 100) as u8
 }
 
+pub fn convert_temp_raw_to_millicelsius(raw: i16) -> i32 {
+    // The raw value contains 8 fractional bits
+    i32::from(raw) * 1000 / 256
+}
+
+pub fn convert_millicelsius_to_temp_raw(temp: i32, mask: u16) -> i64 {
+    round_temp_raw(i64::from(temp) * 256, 1000, mask, Rounding::Truncate)
+}
+
+pub fn convert_celsius_to_temp_raw(temp: f32) -> Option<i16> {
+    // Truncate towards zero to the register step of 1/256ºC
+    let raw = temp * 256.0;
+    if raw.is_finite() && raw > f32::from(i16::MIN) - 1.0 && raw < f32::from(i16::MAX) + 1.0 {
+        Some(raw as i16)
+    } else {
+        None
+    }
+}
+
+pub fn round_temp_raw(numerator: i64, denominator: i64, mask: u16, rounding: Rounding) -> i64 {
+    // Round the raw value given as a fraction to the resolution given by the mask
+    let divisor = denominator * i64::from((!mask).wrapping_add(1));
+    let steps = match rounding {
+        Rounding::Truncate => numerator / divisor,
+        Rounding::Nearest => (2 * numerator + numerator.signum() * divisor) / (2 * divisor),
+    };
+    steps * (divisor / denominator)
+}
+
+pub fn convert_celsius_to_fahrenheit(temp: f32) -> f32 {
+    temp * 9.0 / 5.0 + 32.0
+}
+
+pub fn convert_fahrenheit_to_celsius(temp: f32) -> f32 {
+    (temp - 32.0) * 5.0 / 9.0
+}
+
+pub fn convert_celsius_to_kelvin(temp: f32) -> f32 {
+    temp + 273.15
+}
+
+pub fn convert_kelvin_to_celsius(temp: f32) -> f32 {
+    temp - 273.15
+}
+
+pub fn convert_temp_to_display_decimals(temp: f32) -> usize {
+    // Use one decimal if it represents the value exactly, otherwise two.
+    let scaled = temp * 10.0;
+    if scaled == scaled as i32 as f32 {
+        1
+    } else {
+        2
+    }
+}
+
+pub fn convert_resolution_to_display_decimals(resolution: f32) -> usize {
+    // One decimal for 0.5ºC or coarser steps, otherwise two.
+    if resolution >= 0.5 {
+        1
+    } else {
+        2
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::markers::BitMasks;
+    use crate::{markers::BitMasks, Resolution, Rounding};
+
+    fn to_register(temp: f32, mask: u16) -> (u8, u8) {
+        convert_temp_raw_to_register(
+            convert_temp_to_raw(temp, mask, Rounding::Truncate) as i16,
+            mask,
+        )
+    }
 
     macro_rules! assert_near {
         ($a:expr, $b:expr) => {
@@ -38,6 +136,19 @@ mod tests {
         };
     }
 
+    #[test]
+    fn can_convert_celsius_to_temp_raw() {
+        assert_eq!(Some(0x1980), convert_celsius_to_temp_raw(25.5));
+        assert_eq!(Some(-0x1980), convert_celsius_to_temp_raw(-25.5));
+        assert_eq!(Some(0x1980), convert_celsius_to_temp_raw(25.502));
+        assert_eq!(Some(i16::MAX), convert_celsius_to_temp_raw(127.999));
+        assert_eq!(Some(i16::MIN), convert_celsius_to_temp_raw(-128.0));
+        assert_eq!(None, convert_celsius_to_temp_raw(128.0));
+        assert_eq!(None, convert_celsius_to_temp_raw(-128.01));
+        assert_eq!(None, convert_celsius_to_temp_raw(f32::NAN));
+        assert_eq!(None, convert_celsius_to_temp_raw(f32::INFINITY));
+    }
+
     #[test]
     fn can_convert_temperature_from_register() {
         assert_near!(
@@ -169,114 +280,179 @@ mod tests {
             convert_temp_from_register(0b1000_0000, 0b0101_1010, BitMasks::RESOLUTION_11BIT),
             -127.750
         );
+        assert_near!(
+            convert_temp_from_register(0b1111_1101, 0b1101_1010, BitMasks::RESOLUTION_12BIT),
+            -2.1875
+        );
+    }
+
+    #[test]
+    fn can_convert_raw_temperature_from_register() {
+        assert_eq!(
+            0x1980,
+            convert_temp_raw_from_register(0b0001_1001, 0b1101_1010, BitMasks::RESOLUTION_9BIT)
+        );
+        assert_eq!(
+            0x19C0,
+            convert_temp_raw_from_register(0b0001_1001, 0b1101_1010, BitMasks::RESOLUTION_11BIT)
+        );
+        assert_eq!(
+            -0x80,
+            convert_temp_raw_from_register(0b1111_1111, 0b1101_1010, BitMasks::RESOLUTION_9BIT)
+        );
+        assert_eq!(
+            -0x40,
+            convert_temp_raw_from_register(0b1111_1111, 0b1101_1010, BitMasks::RESOLUTION_11BIT)
+        );
     }
 
     #[test]
     fn can_convert_temperature_to_register() {
         assert_eq!(
             (0b0000_0010, 0b0000_0000),
-            convert_temp_to_register(2.4, BitMasks::RESOLUTION_9BIT)
+            to_register(2.4, BitMasks::RESOLUTION_9BIT)
         );
         assert_eq!(
             (0b0000_0010, 0b1000_0000),
-            convert_temp_to_register(2.6, BitMasks::RESOLUTION_9BIT)
+            to_register(2.6, BitMasks::RESOLUTION_9BIT)
         );
         assert_eq!(
             (0b1111_1110, 0b0000_0000),
-            convert_temp_to_register(-2.0, BitMasks::RESOLUTION_9BIT)
+            to_register(-2.0, BitMasks::RESOLUTION_9BIT)
         );
         assert_eq!(
             (0b1111_1101, 0b1000_0000),
-            convert_temp_to_register(-2.6, BitMasks::RESOLUTION_9BIT)
+            to_register(-2.6, BitMasks::RESOLUTION_9BIT)
         );
         assert_eq!(
             (0b0111_1101, 0b0000_0000),
-            convert_temp_to_register(125.0, BitMasks::RESOLUTION_9BIT)
+            to_register(125.0, BitMasks::RESOLUTION_9BIT)
         );
         assert_eq!(
             (0b0001_1001, 0b0000_0000),
-            convert_temp_to_register(25.0, BitMasks::RESOLUTION_9BIT)
+            to_register(25.0, BitMasks::RESOLUTION_9BIT)
         );
         assert_eq!(
             (0b1110_0111, 0b0000_0000),
-            convert_temp_to_register(-25.0, BitMasks::RESOLUTION_9BIT)
+            to_register(-25.0, BitMasks::RESOLUTION_9BIT)
         );
         assert_eq!(
             (0b1100_1001, 0b0000_0000),
-            convert_temp_to_register(-55.0, BitMasks::RESOLUTION_9BIT)
+            to_register(-55.0, BitMasks::RESOLUTION_9BIT)
         );
         assert_eq!(
             (0b0000_0000, 0b0000_0000),
-            convert_temp_to_register(0.0, BitMasks::RESOLUTION_9BIT)
+            to_register(0.0, BitMasks::RESOLUTION_9BIT)
         );
         assert_eq!(
             (0b0000_0000, 0b1000_0000),
-            convert_temp_to_register(0.5, BitMasks::RESOLUTION_9BIT)
+            to_register(0.5, BitMasks::RESOLUTION_9BIT)
         );
         assert_eq!(
             (0b0010_0000, 0b0000_0000),
-            convert_temp_to_register(32.0, BitMasks::RESOLUTION_9BIT)
+            to_register(32.0, BitMasks::RESOLUTION_9BIT)
         );
         assert_eq!(
             (0b0111_1111, 0b1000_0000),
-            convert_temp_to_register(127.5, BitMasks::RESOLUTION_9BIT)
+            to_register(127.5, BitMasks::RESOLUTION_9BIT)
         );
         assert_eq!(
             (0b1000_0000, 0b0000_0000),
-            convert_temp_to_register(-128.0, BitMasks::RESOLUTION_9BIT)
+            to_register(-128.0, BitMasks::RESOLUTION_9BIT)
         );
 
         assert_eq!(
             (0b0000_0010, 0b0110_0000),
-            convert_temp_to_register(2.4, BitMasks::RESOLUTION_11BIT)
+            to_register(2.4, BitMasks::RESOLUTION_11BIT)
         );
         assert_eq!(
             (0b0000_0010, 0b1000_0000),
-            convert_temp_to_register(2.6, BitMasks::RESOLUTION_11BIT)
+            to_register(2.6, BitMasks::RESOLUTION_11BIT)
         );
         assert_eq!(
             (0b1111_1110, 0b0000_0000),
-            convert_temp_to_register(-2.0, BitMasks::RESOLUTION_11BIT)
+            to_register(-2.0, BitMasks::RESOLUTION_11BIT)
         );
         assert_eq!(
             (0b1111_1101, 0b1000_0000),
-            convert_temp_to_register(-2.6, BitMasks::RESOLUTION_11BIT)
+            to_register(-2.6, BitMasks::RESOLUTION_11BIT)
         );
         assert_eq!(
             (0b0111_1101, 0b0000_0000),
-            convert_temp_to_register(125.0, BitMasks::RESOLUTION_11BIT)
+            to_register(125.0, BitMasks::RESOLUTION_11BIT)
         );
         assert_eq!(
             (0b0001_1001, 0b0000_0000),
-            convert_temp_to_register(25.0, BitMasks::RESOLUTION_11BIT)
+            to_register(25.0, BitMasks::RESOLUTION_11BIT)
         );
         assert_eq!(
             (0b1110_0111, 0b0000_0000),
-            convert_temp_to_register(-25.0, BitMasks::RESOLUTION_11BIT)
+            to_register(-25.0, BitMasks::RESOLUTION_11BIT)
         );
         assert_eq!(
             (0b1100_1001, 0b0000_0000),
-            convert_temp_to_register(-55.0, BitMasks::RESOLUTION_11BIT)
+            to_register(-55.0, BitMasks::RESOLUTION_11BIT)
         );
         assert_eq!(
             (0b0000_0000, 0b0000_0000),
-            convert_temp_to_register(0.0, BitMasks::RESOLUTION_11BIT)
+            to_register(0.0, BitMasks::RESOLUTION_11BIT)
         );
         assert_eq!(
             (0b0000_0000, 0b1000_0000),
-            convert_temp_to_register(0.5, BitMasks::RESOLUTION_11BIT)
+            to_register(0.5, BitMasks::RESOLUTION_11BIT)
         );
         assert_eq!(
             (0b0010_0000, 0b0000_0000),
-            convert_temp_to_register(32.0, BitMasks::RESOLUTION_11BIT)
+            to_register(32.0, BitMasks::RESOLUTION_11BIT)
         );
         assert_eq!(
             (0b0111_1111, 0b1000_0000),
-            convert_temp_to_register(127.5, BitMasks::RESOLUTION_11BIT)
+            to_register(127.5, BitMasks::RESOLUTION_11BIT)
         );
         assert_eq!(
             (0b1000_0000, 0b0000_0000),
-            convert_temp_to_register(-128.0, BitMasks::RESOLUTION_11BIT)
+            to_register(-128.0, BitMasks::RESOLUTION_11BIT)
+        );
+    }
+
+    #[test]
+    fn can_convert_resolution_from_mask() {
+        assert_eq!(0.5, convert_resolution_from_mask(BitMasks::RESOLUTION_9BIT));
+        assert_eq!(
+            0.125,
+            convert_resolution_from_mask(BitMasks::RESOLUTION_11BIT)
+        );
+        assert_eq!(
+            0.0625,
+            convert_resolution_from_mask(BitMasks::RESOLUTION_12BIT)
+        );
+    }
+
+    #[test]
+    fn can_convert_resolution_to_mask() {
+        assert_eq!(
+            BitMasks::RESOLUTION_9BIT,
+            convert_resolution_to_mask(Resolution::Bits9)
+        );
+        assert_eq!(
+            0.0625,
+            convert_resolution_from_mask(convert_resolution_to_mask(Resolution::Bits12))
+        );
+    }
+
+    #[test]
+    fn can_convert_raw_temperature_to_register() {
+        assert_eq!(
+            (0b0001_1001, 0b1000_0000),
+            convert_temp_raw_to_register(0x19E0, BitMasks::RESOLUTION_9BIT)
+        );
+        assert_eq!(
+            (0b0001_1001, 0b1110_0000),
+            convert_temp_raw_to_register(0x19E0, BitMasks::RESOLUTION_11BIT)
+        );
+        assert_eq!(
+            (0b1111_1111, 0b1000_0000),
+            convert_temp_raw_to_register(-0x80, BitMasks::RESOLUTION_9BIT)
         );
     }
 
@@ -293,4 +469,103 @@ mod tests {
         assert_eq!(convert_sample_rate_to_register(1600), 0b0001_0000);
         assert_eq!(convert_sample_rate_to_register(100), 0b0000_0001);
     }
+
+    #[test]
+    fn can_convert_temp_to_display_decimals() {
+        assert_eq!(convert_temp_to_display_decimals(25.0), 1);
+        assert_eq!(convert_temp_to_display_decimals(-24.5), 1);
+        assert_eq!(convert_temp_to_display_decimals(25.25), 2);
+        assert_eq!(convert_temp_to_display_decimals(-0.125), 2);
+        assert_eq!(convert_temp_to_display_decimals(25.03125), 2);
+    }
+
+    #[test]
+    fn can_convert_resolution_to_display_decimals() {
+        assert_eq!(convert_resolution_to_display_decimals(1.0), 1);
+        assert_eq!(convert_resolution_to_display_decimals(0.5), 1);
+        assert_eq!(convert_resolution_to_display_decimals(0.125), 2);
+        assert_eq!(convert_resolution_to_display_decimals(0.0625), 2);
+    }
+
+    #[test]
+    fn can_convert_temperature_units() {
+        assert_near!(convert_celsius_to_fahrenheit(25.0), 77.0);
+        assert_near!(convert_celsius_to_fahrenheit(-40.0), -40.0);
+        assert_near!(convert_fahrenheit_to_celsius(77.0), 25.0);
+        assert_near!(convert_fahrenheit_to_celsius(32.0), 0.0);
+        assert_near!(convert_celsius_to_kelvin(25.0), 298.15);
+        assert_near!(convert_kelvin_to_celsius(273.15), 0.0);
+    }
+
+    #[test]
+    fn can_round_temperature_to_nearest() {
+        let mask = BitMasks::RESOLUTION_9BIT;
+        assert_eq!(0x280, convert_temp_to_raw(2.6, mask, Rounding::Nearest));
+        assert_eq!(0x300, convert_temp_to_raw(2.8, mask, Rounding::Nearest));
+        assert_eq!(0x200, convert_temp_to_raw(2.2, mask, Rounding::Nearest));
+        assert_eq!(-0x300, convert_temp_to_raw(-2.8, mask, Rounding::Nearest));
+        assert_eq!(-0x280, convert_temp_to_raw(-2.6, mask, Rounding::Nearest));
+        assert_eq!(0x280, convert_temp_to_raw(2.8, mask, Rounding::Truncate));
+        assert_eq!(-0x280, convert_temp_to_raw(-2.8, mask, Rounding::Truncate));
+    }
+
+    #[test]
+    fn can_round_temperature_raw() {
+        let mask = BitMasks::RESOLUTION_9BIT;
+        assert_eq!(
+            0x300,
+            round_temp_raw(2800 * 256, 1000, mask, Rounding::Nearest)
+        );
+        assert_eq!(
+            0x280,
+            round_temp_raw(2700 * 256, 1000, mask, Rounding::Nearest)
+        );
+        assert_eq!(
+            -0x300,
+            round_temp_raw(-2800 * 256, 1000, mask, Rounding::Nearest)
+        );
+        assert_eq!(
+            0x280,
+            round_temp_raw(2800 * 256, 1000, mask, Rounding::Truncate)
+        );
+        assert_eq!(0x300, round_temp_raw(0x2C0, 1, mask, Rounding::Nearest));
+        assert_eq!(0x280, round_temp_raw(0x2BF, 1, mask, Rounding::Nearest));
+        assert_eq!(-0x300, round_temp_raw(-0x2C0, 1, mask, Rounding::Nearest));
+        assert_eq!(
+            0x12,
+            round_temp_raw(0x12, 1, BitMasks::RESOLUTION_16BIT, Rounding::Nearest)
+        );
+    }
+
+    #[test]
+    fn can_convert_temperature_raw_to_millicelsius() {
+        assert_eq!(convert_temp_raw_to_millicelsius(0x1980), 25500);
+        assert_eq!(convert_temp_raw_to_millicelsius(0x1910), 25062);
+        assert_eq!(convert_temp_raw_to_millicelsius(-0x1980), -25500);
+        assert_eq!(convert_temp_raw_to_millicelsius(0), 0);
+    }
+
+    #[test]
+    fn can_convert_millicelsius_to_temperature_raw() {
+        assert_eq!(
+            convert_millicelsius_to_temp_raw(25500, BitMasks::RESOLUTION_9BIT),
+            0x1980
+        );
+        assert_eq!(
+            convert_millicelsius_to_temp_raw(25900, BitMasks::RESOLUTION_9BIT),
+            0x1980
+        );
+        assert_eq!(
+            convert_millicelsius_to_temp_raw(-300, BitMasks::RESOLUTION_9BIT),
+            0
+        );
+        assert_eq!(
+            convert_millicelsius_to_temp_raw(25_063, BitMasks::RESOLUTION_12BIT),
+            0x1910
+        );
+        assert_eq!(
+            convert_millicelsius_to_temp_raw(-55_000, BitMasks::RESOLUTION_9BIT),
+            -55 * 256
+        );
+    }
 }
@@ -1,16 +1,47 @@
-use crate::markers::ResolutionSupport;
-use crate::{conversion, ic, Address, Config, Error, FaultQueue, Lm75, OsMode, OsPolarity};
-use core::marker::PhantomData;
-use embedded_hal::i2c;
+use crate::markers::{
+    HasOneShot, HasProductId, HasResolutionConfig, HasSampleRate, RegisterSupport,
+    ResolutionSupport,
+};
+#[cfg(feature = "tmp1075")]
+use crate::ConversionRate;
+use crate::{
+    conversion, ic, Access, Address, AddressSet, AlertConfig, AlertEvent, AlertMonitor,
+    AutoShutdown, Capabilities, Celsius, Config, ConfigUpdate, DeviceState, Error, FaultQueue,
+    GroupReading, InterruptMode, IntoTemperature, Lm75, Lm75Group, Lm75Like, Measurement, OsMode,
+    OsPolarity, PowerMode, PowerProfile, Register, RegisterDump, Resolution, Rounding, Sampler,
+    Settings, Temperature, TemperatureSensor, Variant, WaitOutcome, GROUP_CAPACITY,
+};
+use embedded_hal::{
+    delay::DelayNs,
+    digital::InputPin,
+    i2c::{self, ErrorKind, I2c, Operation},
+};
+#[cfg(feature = "fixed")]
+use fixed::{
+    traits::{FromFixed, ToFixed},
+    types::I16F16,
+};
+#[cfg(feature = "uom")]
+use uom::si::f32::ThermodynamicTemperature;
+// The marker is renamed because the idents named as the driver are converted
+// for the asynchronous version.
+use ic::Lm75 as Lm75Marker;
+#[cfg(feature = "async")]
+use {
+    crate::{
+        AlertMonitorAsync, AutoShutdownAsync, InterruptModeAsync, Lm75Async, Lm75GroupAsync,
+        SamplerAsync,
+    },
+    embedded_hal_async::{delay::DelayNs as AsyncDelayNs, digital::Wait, i2c::I2c as AsyncI2c},
+    futures_util::stream::{self, Stream},
+};
 
-struct Register;
-
-impl Register {
-    const TEMPERATURE: u8 = 0x00;
-    const CONFIGURATION: u8 = 0x01;
-    const T_HYST: u8 = 0x02;
-    const T_OS: u8 = 0x03;
-    const T_IDLE: u8 = 0x04;
+/// emit a trace event if the `log` feature is enabled
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "log")]
+        log::trace!($($arg)*);
+    };
 }
 
 struct BitFlags;
@@ -21,179 +52,3296 @@ impl BitFlags {
     const OS_POLARITY: u8 = 0b0000_0100;
     const FAULT_QUEUE0: u8 = 0b0000_1000;
     const FAULT_QUEUE1: u8 = 0b0001_0000;
+    const RESOLUTION0: u8 = 0b0010_0000;
+    const RESOLUTION1: u8 = 0b0100_0000;
+    const ONE_SHOT: u8 = 0b1000_0000;
+    #[cfg(feature = "tmp1075")]
+    const CONVERSION_RATE0: u8 = 0b0010_0000;
+    #[cfg(feature = "tmp1075")]
+    const CONVERSION_RATE1: u8 = 0b0100_0000;
+    #[cfg(feature = "max3172x")]
+    const EXTENDED_FORMAT: u8 = 0b0010_0000;
+    #[cfg(feature = "ds7505")]
+    const NV_BUSY: u8 = 0b1000_0000;
+    #[cfg(feature = "max750x")]
+    const BUS_TIMEOUT_DISABLE: u8 = 0b1000_0000;
+    // In the second byte of the AT30TS75A configuration register
+    #[cfg(feature = "at30ts75a")]
+    const NV_REGISTER_BUSY: u8 = 0b0000_0001;
+    // In the second byte of the AT30TS75A nonvolatile configuration register
+    #[cfg(feature = "at30ts75a")]
+    const REGISTER_LOCK: u8 = 0b0000_0010;
+    #[cfg(feature = "at30ts75a")]
+    const REGISTER_LOCKDOWN: u8 = 0b0000_0100;
 }
 
-impl<I2C, E> Lm75<I2C, ic::Lm75>
-where
-    I2C: i2c::I2c<Error = E>,
-{
-    /// Create new instance of the LM75 device.
-    pub fn new<A: Into<Address>>(i2c: I2C, address: A) -> Self {
-        let a = address.into();
-        Lm75 {
-            i2c,
-            address: a.0,
-            config: Config::default(),
-            _ic: PhantomData,
-        }
+/// TMP1075 device ID register
+#[cfg(feature = "tmp1075")]
+const DEVICE_ID: Register = Register::Other(0x0F);
+
+/// SE95 manufacturer ID register
+#[cfg(feature = "se95")]
+const MANUFACTURER_ID: Register = Register::Other(0x07);
+
+/// LM75A product ID register
+const PRODUCT_ID: Register = Register::Other(0x07);
+
+/// AT30TS75A nonvolatile configuration register
+#[cfg(feature = "at30ts75a")]
+const NV_CONFIGURATION: Register = Register::Other(0x11);
+
+#[cfg(any(feature = "ds7505", feature = "at30ts75a"))]
+struct Command;
+
+#[cfg(any(feature = "ds7505", feature = "at30ts75a"))]
+impl Command {
+    const COPY_DATA: u8 = 0x48;
+    #[cfg(feature = "ds7505")]
+    const SOFTWARE_POR: u8 = 0x54;
+    #[cfg(feature = "at30ts75a")]
+    const RECALL_DATA: u8 = 0xB8;
+}
+
+/// check that a temperature (celsius) is a number within the operating range
+/// and can be stored in a register with the given offset (celsius)
+#[allow(clippy::manual_range_contains)]
+fn check_temperature<E>(temperature: f32, range: (i16, i16), offset: i16) -> Result<(), Error<E>> {
+    if !temperature.is_finite() {
+        return Err(Error::InvalidInputData);
+    }
+    let register = temperature - f32::from(offset);
+    if temperature >= f32::from(range.0)
+        && temperature <= f32::from(range.1)
+        && register >= -128.0
+        && register < 128.0
+    {
+        Ok(())
+    } else {
+        Err(Error::OutOfRange)
     }
 }
 
-impl<I2C, IC> Lm75<I2C, IC> {
-    /// Destroy driver instance, return I²C bus instance.
-    pub fn destroy(self) -> I2C {
-        self.i2c
+fn is_valid_temperature_raw(raw: i32, range: (i16, i16)) -> bool {
+    (i32::from(range.0) * 256..=i32::from(range.1) * 256).contains(&raw)
+}
+
+#[allow(clippy::manual_is_multiple_of)]
+fn check_sample_rate<E>(period: u16) -> Result<(), Error<E>> {
+    if period > 3100 {
+        Err(Error::OutOfRange)
+    } else if period % 100 != 0 {
+        Err(Error::InvalidInputData)
+    } else {
+        Ok(())
     }
 }
 
-impl<I2C, IC, E> Lm75<I2C, IC>
-where
-    I2C: i2c::I2c<Error = E>,
-    IC: ResolutionSupport<E>,
-{
-    /// Enable the sensor (default state).
-    pub fn enable(&mut self) -> Result<(), Error<E>> {
-        let config = self.config;
-        self.write_config(config.with_low(BitFlags::SHUTDOWN))
+impl Config {
+    /// Whether the device is shut down.
+    pub fn is_shutdown(&self) -> bool {
+        (self.bits & BitFlags::SHUTDOWN) != 0
     }
 
-    /// Disable the sensor (shutdown).
-    pub fn disable(&mut self) -> Result<(), Error<E>> {
-        let config = self.config;
-        self.write_config(config.with_high(BitFlags::SHUTDOWN))
+    /// Fault queue.
+    pub fn fault_queue(&self) -> FaultQueue {
+        match (
+            (self.bits & BitFlags::FAULT_QUEUE1) != 0,
+            (self.bits & BitFlags::FAULT_QUEUE0) != 0,
+        ) {
+            (false, false) => FaultQueue::_1,
+            (false, true) => FaultQueue::_2,
+            (true, false) => FaultQueue::_4,
+            (true, true) => FaultQueue::_6,
+        }
     }
 
-    /// Set the fault queue.
+    /// OS polarity.
+    pub fn os_polarity(&self) -> OsPolarity {
+        if (self.bits & BitFlags::OS_POLARITY) != 0 {
+            OsPolarity::ActiveHigh
+        } else {
+            OsPolarity::ActiveLow
+        }
+    }
+
+    /// OS operation mode.
+    pub fn os_mode(&self) -> OsMode {
+        if (self.bits & BitFlags::COMP_INT) != 0 {
+            OsMode::Interrupt
+        } else {
+            OsMode::Comparator
+        }
+    }
+
+    /// Temperature resolution.
     ///
-    /// Set the number of consecutive faults that will trigger an OS condition.
-    pub fn set_fault_queue(&mut self, fq: FaultQueue) -> Result<(), Error<E>> {
-        let config = self.config;
+    /// This is only meaningful for devices with configurable resolution.
+    pub fn resolution(&self) -> Resolution {
+        match (
+            (self.bits & BitFlags::RESOLUTION1) != 0,
+            (self.bits & BitFlags::RESOLUTION0) != 0,
+        ) {
+            (false, false) => Resolution::Bits9,
+            (false, true) => Resolution::Bits10,
+            (true, false) => Resolution::Bits11,
+            (true, true) => Resolution::Bits12,
+        }
+    }
+
+    /// whether the extended data format is selected (MAX31725/6)
+    #[cfg(feature = "max3172x")]
+    pub(crate) fn is_extended_format(&self) -> bool {
+        (self.bits & BitFlags::EXTENDED_FORMAT) != 0
+    }
+
+    /// Set whether the device is shut down.
+    pub fn with_shutdown(self, shutdown: bool) -> Self {
+        if shutdown {
+            self.with_high(BitFlags::SHUTDOWN)
+        } else {
+            self.with_low(BitFlags::SHUTDOWN)
+        }
+    }
+
+    /// Set the fault queue.
+    pub fn with_fault_queue(self, fq: FaultQueue) -> Self {
         match fq {
-            FaultQueue::_1 => self.write_config(
-                config
-                    .with_low(BitFlags::FAULT_QUEUE1)
-                    .with_low(BitFlags::FAULT_QUEUE0),
-            ),
-            FaultQueue::_2 => self.write_config(
-                config
-                    .with_low(BitFlags::FAULT_QUEUE1)
-                    .with_high(BitFlags::FAULT_QUEUE0),
-            ),
-            FaultQueue::_4 => self.write_config(
-                config
-                    .with_high(BitFlags::FAULT_QUEUE1)
-                    .with_low(BitFlags::FAULT_QUEUE0),
-            ),
-            FaultQueue::_6 => self.write_config(
-                config
-                    .with_high(BitFlags::FAULT_QUEUE1)
-                    .with_high(BitFlags::FAULT_QUEUE0),
-            ),
+            FaultQueue::_1 => self
+                .with_low(BitFlags::FAULT_QUEUE1)
+                .with_low(BitFlags::FAULT_QUEUE0),
+            FaultQueue::_2 => self
+                .with_low(BitFlags::FAULT_QUEUE1)
+                .with_high(BitFlags::FAULT_QUEUE0),
+            FaultQueue::_4 => self
+                .with_high(BitFlags::FAULT_QUEUE1)
+                .with_low(BitFlags::FAULT_QUEUE0),
+            FaultQueue::_6 => self
+                .with_high(BitFlags::FAULT_QUEUE1)
+                .with_high(BitFlags::FAULT_QUEUE0),
         }
     }
 
     /// Set the OS polarity.
-    pub fn set_os_polarity(&mut self, polarity: OsPolarity) -> Result<(), Error<E>> {
-        let config = self.config;
+    pub fn with_os_polarity(self, polarity: OsPolarity) -> Self {
         match polarity {
-            OsPolarity::ActiveLow => self.write_config(config.with_low(BitFlags::OS_POLARITY)),
-            OsPolarity::ActiveHigh => self.write_config(config.with_high(BitFlags::OS_POLARITY)),
+            OsPolarity::ActiveLow => self.with_low(BitFlags::OS_POLARITY),
+            OsPolarity::ActiveHigh => self.with_high(BitFlags::OS_POLARITY),
         }
     }
 
     /// Set the OS operation mode.
-    pub fn set_os_mode(&mut self, mode: OsMode) -> Result<(), Error<E>> {
-        let config = self.config;
+    pub fn with_os_mode(self, mode: OsMode) -> Self {
         match mode {
-            OsMode::Comparator => self.write_config(config.with_low(BitFlags::COMP_INT)),
-            OsMode::Interrupt => self.write_config(config.with_high(BitFlags::COMP_INT)),
+            OsMode::Comparator => self.with_low(BitFlags::COMP_INT),
+            OsMode::Interrupt => self.with_high(BitFlags::COMP_INT),
         }
     }
 
-    /// Set the OS temperature (celsius).
-    #[allow(clippy::manual_range_contains)]
-    pub fn set_os_temperature(&mut self, temperature: f32) -> Result<(), Error<E>> {
-        if temperature < -55.0 || temperature > 125.0 {
-            return Err(Error::InvalidInputData);
+    /// Set the temperature resolution.
+    ///
+    /// This is only meaningful for devices with configurable resolution.
+    pub fn with_resolution(self, resolution: Resolution) -> Self {
+        match resolution {
+            Resolution::Bits9 => self
+                .with_low(BitFlags::RESOLUTION1)
+                .with_low(BitFlags::RESOLUTION0),
+            Resolution::Bits10 => self
+                .with_low(BitFlags::RESOLUTION1)
+                .with_high(BitFlags::RESOLUTION0),
+            Resolution::Bits11 => self
+                .with_high(BitFlags::RESOLUTION1)
+                .with_low(BitFlags::RESOLUTION0),
+            Resolution::Bits12 => self
+                .with_high(BitFlags::RESOLUTION1)
+                .with_high(BitFlags::RESOLUTION0),
         }
-        let (msb, lsb) =
-            conversion::convert_temp_to_register(temperature, IC::get_resolution_mask());
-        self.i2c
-            .write(self.address, &[Register::T_OS, msb, lsb])
-            .map_err(Error::I2C)
     }
+}
 
-    /// Set the hysteresis temperature (celsius).
-    #[allow(clippy::manual_range_contains)]
-    pub fn set_hysteresis_temperature(&mut self, temperature: f32) -> Result<(), Error<E>> {
-        if temperature < -55.0 || temperature > 125.0 {
-            return Err(Error::InvalidInputData);
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, E> Lm75<I2C, Lm75Marker>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create new instance of the LM75 device.
+    pub fn new<A: Into<Address>>(i2c: I2C, address: A) -> Self {
+        Self::create(i2c, address.into(), Lm75Marker)
+    }
+
+    /// Scan the bus for LM75-family devices.
+    ///
+    /// All addresses selectable on the PCT2075 are probed, which include
+    /// those of the LM75 and most compatible devices. Returns the addresses
+    /// acknowledged. See `Address::pct2075_range()` and `probe()`.
+    pub async fn scan(i2c: &mut I2C) -> Result<AddressSet, Error<E>>
+    where
+        E: i2c::Error,
+    {
+        let mut found = AddressSet::default();
+        for address in Address::pct2075_range() {
+            if Lm75::new(&mut *i2c, address).probe().await? {
+                found.insert(address);
+            }
         }
-        let (msb, lsb) =
-            conversion::convert_temp_to_register(temperature, IC::get_resolution_mask());
-        self.i2c
-            .write(self.address, &[Register::T_HYST, msb, lsb])
-            .map_err(Error::I2C)
+        Ok(found)
     }
 
-    /// Read the temperature from the sensor (celsius).
-    pub fn read_temperature(&mut self) -> Result<f32, Error<E>> {
-        let mut data = [0; 2];
-        self.i2c
-            .write_read(self.address, &[Register::TEMPERATURE], &mut data)
-            .map_err(Error::I2C)?;
-        Ok(conversion::convert_temp_from_register(
-            data[0],
-            data[1],
-            IC::get_resolution_mask(),
-        ))
+    /// Create new instance of the LM75 device reading the current
+    /// configuration from the device instead of assuming power-on defaults.
+    ///
+    /// This is useful when the device may have been configured before,
+    /// for example if the MCU was reset but the sensor kept its power.
+    pub async fn new_synced<A: Into<Address>>(i2c: I2C, address: A) -> Result<Self, Error<E>> {
+        let mut sensor = Self::new(i2c, address);
+        sensor.config = sensor.read_config().await?;
+        Ok(sensor)
     }
+}
 
-    /// write configuration to device
-    fn write_config(&mut self, config: Config) -> Result<(), Error<E>> {
-        self.i2c
-            .write(self.address, &[Register::CONFIGURATION, config.bits])
-            .map_err(Error::I2C)?;
-        self.config = config;
-        Ok(())
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, E> Lm75<I2C, ic::Dynamic>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create new instance of a device whose variant is selected at runtime.
+    ///
+    /// Temperature readings and limits are handled according to the variant.
+    /// Variant-specific functionality like setting the resolution is only
+    /// available through the dedicated device types.
+    pub fn new_dynamic<A: Into<Address>>(i2c: I2C, address: A, variant: Variant) -> Self {
+        Self::create(i2c, address.into(), ic::Dynamic(variant))
+    }
+
+    /// Create new instance of a device whose variant is selected at runtime
+    /// reading the current configuration from the device instead of
+    /// assuming power-on defaults.
+    ///
+    /// This is useful when the device may have been configured before,
+    /// for example if the MCU was reset but the sensor kept its power.
+    pub async fn new_dynamic_synced<A: Into<Address>>(
+        i2c: I2C,
+        address: A,
+        variant: Variant,
+    ) -> Result<Self, Error<E>> {
+        let mut sensor = Self::new_dynamic(i2c, address, variant);
+        sensor.config = sensor.read_config().await?;
+        Ok(sensor)
+    }
+
+    /// Get the device variant.
+    pub fn variant(&self) -> Variant {
+        self.ic.0
     }
 }
 
-impl<I2C, E> Lm75<I2C, ic::Pct2075>
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, E> Lm75<I2C, ic::Generic>
 where
-    I2C: i2c::I2c<Error = E>,
+    I2C: I2c<Error = E>,
 {
-    /// Create new instance of the PCT2075 device.
-    pub fn new_pct2075<A: Into<Address>>(i2c: I2C, address: A) -> Self {
-        let a = address.into();
+    /// Create new instance of a generic LM75-compatible device with the
+    /// given temperature resolution.
+    ///
+    /// This is useful for compatible devices not supported explicitly
+    /// whose register format is known. The OS and hysteresis temperatures
+    /// use the same resolution.
+    pub fn new_generic<A: Into<Address>>(i2c: I2C, address: A, resolution: Resolution) -> Self {
+        Self::create(i2c, address.into(), ic::Generic(resolution))
+    }
+
+    /// Create new instance of a generic LM75-compatible device with the
+    /// given temperature resolution reading the current configuration
+    /// from the device instead of assuming power-on defaults.
+    ///
+    /// This is useful when the device may have been configured before,
+    /// for example if the MCU was reset but the sensor kept its power.
+    pub async fn new_generic_synced<A: Into<Address>>(
+        i2c: I2C,
+        address: A,
+        resolution: Resolution,
+    ) -> Result<Self, Error<E>> {
+        let mut sensor = Self::new_generic(i2c, address, resolution);
+        sensor.config = sensor.read_config().await?;
+        Ok(sensor)
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, IC, E> Lm75<I2C, IC>
+where
+    I2C: I2c<Error = E>,
+    IC: ResolutionSupport,
+{
+    /// Create new instance of the device described by the given marker.
+    ///
+    /// This allows using device variants defined outside of this crate
+    /// by implementing the traits of the [`ic`] module.
+    pub fn new_custom<A: Into<Address>>(i2c: I2C, address: A, ic: IC) -> Self {
+        Self::create(i2c, address.into(), ic)
+    }
+
+    /// Create new instance of the device described by the given marker
+    /// reading the current configuration from the device instead of
+    /// assuming power-on defaults.
+    ///
+    /// This is useful when the device may have been configured before,
+    /// for example if the MCU was reset but the sensor kept its power.
+    pub async fn new_custom_synced<A: Into<Address>>(
+        i2c: I2C,
+        address: A,
+        ic: IC,
+    ) -> Result<Self, Error<E>> {
+        let mut sensor = Self::new_custom(i2c, address, ic);
+        sensor.config = sensor.read_config().await?;
+        Ok(sensor)
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), AlertMonitor(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, IC> Lm75<I2C, IC> {
+    fn create(i2c: I2C, address: Address, ic: IC) -> Self {
         Lm75 {
             i2c,
-            address: a.0,
+            address: address.0,
             config: Config::default(),
-            _ic: PhantomData,
+            config_update: ConfigUpdate::default(),
+            rounding: Rounding::default(),
+            verify_writes: false,
+            check_readings: false,
+            pointer: None,
+            separate_transfers: false,
+            one_shot_started: false,
+            ic,
         }
     }
 
-    /// Set the sensor sample rate period in milliseconds (100ms increments).
+    /// Set how the configuration register is updated.
     ///
-    /// For values outside of the range `[100 - 3100]` or those not a multiple of 100,
-    /// `Error::InvalidInputData will be returned
-    pub fn set_sample_rate(&mut self, period: u16) -> Result<(), Error<E>> {
-        if period > 3100 || period % 100 != 0 {
-            return Err(Error::InvalidInputData);
+    /// By default, the configuration is cached locally and only written to
+    /// the device. With `ConfigUpdate::ReadModifyWrite` the configuration
+    /// is read from the device before every modification.
+    pub fn with_config_update(mut self, config_update: ConfigUpdate) -> Self {
+        self.config_update = config_update;
+        self
+    }
+
+    /// Set how temperatures are rounded to the device resolution when written.
+    ///
+    /// By default, temperatures are truncated towards zero.
+    /// The temperature setters return the temperature actually written.
+    pub fn with_rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Set whether to verify every register write by reading it back.
+    ///
+    /// If enabled, `Error::WriteVerificationFailed` is returned if the
+    /// contents read back do not match the data written.
+    /// This is disabled by default.
+    pub fn with_verified_writes(mut self, enabled: bool) -> Self {
+        self.verify_writes = enabled;
+        self
+    }
+
+    /// Set whether to check that temperature readings are plausible.
+    ///
+    /// If enabled, `Error::SensorFault` is returned for temperature readings
+    /// outside of the operating range of the device, for example due to a
+    /// damaged sensor or a corrupted transfer. Devices may report valid
+    /// temperatures slightly beyond their operating range, though.
+    /// This is disabled by default.
+    pub fn with_plausibility_check(mut self, enabled: bool) -> Self {
+        self.check_readings = enabled;
+        self
+    }
+
+    /// Set whether to use repeated start conditions.
+    ///
+    /// By default, the register pointer is written and the register read
+    /// within a single transaction using a repeated start condition.
+    /// If disabled, a stop condition is issued between writing the
+    /// register pointer and reading, as well as between the accesses to
+    /// several registers. This is useful for I²C controllers that cannot
+    /// generate repeated start conditions.
+    pub fn with_repeated_start(mut self, enabled: bool) -> Self {
+        self.separate_transfers = !enabled;
+        self
+    }
+
+    /// Attach the input pin connected to the OS (alert) output.
+    ///
+    /// The returned driver interprets the pin level according to the OS
+    /// polarity configured through this driver. See [`AlertMonitor`].
+    pub fn with_os_pin<P>(self, os_pin: P) -> AlertMonitor<I2C, IC, P> {
+        AlertMonitor {
+            sensor: self,
+            os_pin,
+            debounce_samples: 1,
+            pin_active: false,
+            pending_samples: 0,
+            latched: false,
+            alert_active: false,
+            over_temperature: false,
         }
-        let byte = conversion::convert_sample_rate_to_register(period);
+    }
+
+    /// Get the I²C device address.
+    pub fn address(&self) -> Address {
+        Address(self.address)
+    }
+
+    /// Set the I²C device address.
+    ///
+    /// This points the driver at another device, for example after a bus
+    /// topology change. The cached configuration is kept. If the device
+    /// may be configured differently, use `ConfigUpdate::ReadModifyWrite`
+    /// or read the configuration through `read_register()`.
+    pub fn set_address<A: Into<Address>>(&mut self, address: A) {
+        self.address = address.into().0;
+        self.pointer = None;
+        self.one_shot_started = false;
+    }
+
+    /// Wait until the OS (alert) output of the device is active.
+    ///
+    /// The pin connected to the OS output is interpreted according to the
+    /// OS polarity configured through this driver.
+    /// Returns immediately if the OS output is already active.
+    #[maybe_async_cfg::only_if(async)]
+    pub async fn wait_for_alert<P: Wait>(&self, os_pin: &mut P) -> Result<(), P::Error> {
+        match self.config.os_polarity() {
+            OsPolarity::ActiveLow => os_pin.wait_for_low().await,
+            OsPolarity::ActiveHigh => os_pin.wait_for_high().await,
+        }
+    }
+
+    /// Convert into a driver for the device described by the given marker.
+    ///
+    /// The I²C bus, the address, the cached configuration and the driver
+    /// settings are kept. This is useful when the actual device is only
+    /// known after probing it with a generic driver.
+    pub fn into_custom<T>(self, ic: T) -> Lm75<I2C, T> {
+        Lm75 {
+            i2c: self.i2c,
+            address: self.address,
+            config: self.config,
+            config_update: self.config_update,
+            rounding: self.rounding,
+            verify_writes: self.verify_writes,
+            check_readings: self.check_readings,
+            pointer: self.pointer,
+            separate_transfers: self.separate_transfers,
+            one_shot_started: self.one_shot_started,
+            ic,
+        }
+    }
+
+    /// Convert into an LM75 driver keeping the current state.
+    pub fn into_lm75(self) -> Lm75<I2C, Lm75Marker> {
+        self.into_custom(Lm75Marker)
+    }
+
+    /// Convert into a driver whose variant is selected at runtime keeping
+    /// the current state.
+    pub fn into_dynamic(self, variant: Variant) -> Lm75<I2C, ic::Dynamic> {
+        self.into_custom(ic::Dynamic(variant))
+    }
+
+    /// Convert into a driver for a generic LM75-compatible device with the
+    /// given temperature resolution keeping the current state.
+    pub fn into_generic(self, resolution: Resolution) -> Lm75<I2C, ic::Generic> {
+        self.into_custom(ic::Generic(resolution))
+    }
+
+    /// Convert into a PCT2075 driver keeping the current state.
+    #[cfg(feature = "pct2075")]
+    pub fn into_pct2075(self) -> Lm75<I2C, ic::Pct2075> {
+        self.into_custom(ic::Pct2075)
+    }
+
+    /// Convert into an NXP LM75A/LM75B driver keeping the current state.
+    #[cfg(feature = "lm75a")]
+    pub fn into_lm75a(self) -> Lm75<I2C, ic::Lm75a> {
+        self.into_custom(ic::Lm75a)
+    }
+
+    /// Convert into a G751 driver keeping the current state.
+    #[cfg(feature = "g751")]
+    pub fn into_g751(self) -> Lm75<I2C, ic::G751> {
+        self.into_custom(ic::G751)
+    }
+
+    /// Convert into an MCP9800/1/2/3 driver keeping the current state.
+    #[cfg(feature = "mcp980x")]
+    pub fn into_mcp980x(self) -> Lm75<I2C, ic::Mcp980x> {
+        self.into_custom(ic::Mcp980x)
+    }
+
+    /// Convert into a DS7505 driver keeping the current state.
+    #[cfg(feature = "ds7505")]
+    pub fn into_ds7505(self) -> Lm75<I2C, ic::Ds7505> {
+        self.into_custom(ic::Ds7505)
+    }
+
+    /// Convert into an AT30TS75A driver keeping the current state.
+    #[cfg(feature = "at30ts75a")]
+    pub fn into_at30ts75a(self) -> Lm75<I2C, ic::At30ts75a> {
+        self.into_custom(ic::At30ts75a)
+    }
+
+    /// Convert into a MAX7500/1/2/3/4 driver keeping the current state.
+    #[cfg(feature = "max750x")]
+    pub fn into_max750x(self) -> Lm75<I2C, ic::Max750x> {
+        self.into_custom(ic::Max750x)
+    }
+
+    /// Convert into a MAX6625 driver keeping the current state.
+    #[cfg(feature = "max6625")]
+    pub fn into_max6625(self) -> Lm75<I2C, ic::Max6625> {
+        self.into_custom(ic::Max6625)
+    }
+
+    /// Convert into a MAX6626 driver keeping the current state.
+    #[cfg(feature = "max6626")]
+    pub fn into_max6626(self) -> Lm75<I2C, ic::Max6626> {
+        self.into_custom(ic::Max6626)
+    }
+
+    /// Convert into a TCN75A driver keeping the current state.
+    #[cfg(feature = "tcn75a")]
+    pub fn into_tcn75a(self) -> Lm75<I2C, ic::Tcn75a> {
+        self.into_custom(ic::Tcn75a)
+    }
+
+    /// Convert into a TMP75/TMP175/TMP275 driver keeping the current state.
+    #[cfg(feature = "tmp75")]
+    pub fn into_tmp75(self) -> Lm75<I2C, ic::Tmp75> {
+        self.into_custom(ic::Tmp75)
+    }
+
+    /// Convert into a TMP1075 driver keeping the current state.
+    #[cfg(feature = "tmp1075")]
+    pub fn into_tmp1075(self) -> Lm75<I2C, ic::Tmp1075> {
+        self.into_custom(ic::Tmp1075)
+    }
+
+    /// Convert into an STDS75 driver keeping the current state.
+    #[cfg(feature = "stds75")]
+    pub fn into_stds75(self) -> Lm75<I2C, ic::Stds75> {
+        self.into_custom(ic::Stds75)
+    }
+
+    /// Convert into an STLM75 driver keeping the current state.
+    #[cfg(feature = "stlm75")]
+    pub fn into_stlm75(self) -> Lm75<I2C, ic::Stlm75> {
+        self.into_custom(ic::Stlm75)
+    }
+
+    /// Convert into an SE95 driver keeping the current state.
+    #[cfg(feature = "se95")]
+    pub fn into_se95(self) -> Lm75<I2C, ic::Se95> {
+        self.into_custom(ic::Se95)
+    }
+
+    /// Convert into a MAX31725/MAX31726 driver keeping the current state.
+    #[cfg(feature = "max3172x")]
+    pub fn into_max3172x(self) -> Lm75<I2C, ic::Max3172x> {
+        self.into_custom(ic::Max3172x)
+    }
+
+    /// Destroy driver instance, return I²C bus instance.
+    pub fn destroy(self) -> I2C {
         self.i2c
-            .write(self.address, &[Register::T_IDLE, byte])
-            .map_err(Error::I2C)
     }
+}
 
-    /// Read the sample rate period from the sensor (ms).
-    pub fn read_sample_rate(&mut self) -> Result<u16, Error<E>> {
-        let mut data = [0; 1];
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, IC, E> Lm75<I2C, IC>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Check whether a device acknowledges its address.
+    ///
+    /// This reads the configuration register without modifying the device
+    /// or the cached configuration. Returns `false` if the address is not
+    /// acknowledged and an error for any other I²C bus error.
+    /// In interrupt mode, this clears the OS output like any other read.
+    ///
+    /// This is useful for detecting the populated sensors at boot time.
+    pub async fn probe(&mut self) -> Result<bool, Error<E>>
+    where
+        E: i2c::Error,
+    {
+        match self.read_register(Register::Configuration, &mut [0]).await {
+            Ok(()) => Ok(true),
+            Err(Error::I2C { source, .. })
+                if matches!(source.kind(), ErrorKind::NoAcknowledge(_)) =>
+            {
+                Ok(false)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Read the contents of a register.
+    ///
+    /// This is a low-level method intended for advanced use, for example
+    /// to access vendor-specific registers of compatible devices.
+    /// The number of bytes read is given by the length of `data`.
+    pub async fn read_register(
+        &mut self,
+        register: Register,
+        data: &mut [u8],
+    ) -> Result<(), Error<E>> {
+        if self.separate_transfers {
+            let pointer = [register.addr()];
+            return self
+                .transaction(
+                    &mut [Operation::Write(&pointer), Operation::Read(data)],
+                    Access::Read(register),
+                    register,
+                )
+                .await;
+        }
+        self.pointer = None;
         self.i2c
-            .write_read(self.address, &[Register::T_IDLE], &mut data)
-            .map_err(Error::I2C)?;
-        Ok(conversion::convert_sample_rate_from_register(data[0]))
+            .write_read(self.address, &[register.addr()], data)
+            .await
+            .map_err(|source| Error::I2C {
+                access: Access::Read(register),
+                source,
+            })?;
+        trace!(
+            "read {:?} from 0x{:02x}: {:02x?}",
+            register,
+            self.address,
+            data
+        );
+        self.pointer = Some(register);
+        Ok(())
+    }
+
+    /// Read the contents of a register in non-blocking steps.
+    ///
+    /// If the device register pointer is not known to be set to `register`,
+    /// it is set first and `nb::Error::WouldBlock` is returned. The data is
+    /// then read on a subsequent call. Since the register pointer is kept,
+    /// further reads of the same register complete in a single call.
+    #[maybe_async_cfg::only_if(sync)]
+    pub fn try_read_register(
+        &mut self,
+        register: Register,
+        data: &mut [u8],
+    ) -> nb::Result<(), Error<E>> {
+        if self.pointer != Some(register) {
+            self.write_register(register, &[])?;
+            return Err(nb::Error::WouldBlock);
+        }
+        self.pointer = None;
+        self.i2c
+            .read(self.address, data)
+            .map_err(|source| Error::I2C {
+                access: Access::Read(register),
+                source,
+            })?;
+        trace!(
+            "read {:?} from 0x{:02x}: {:02x?}",
+            register,
+            self.address,
+            data
+        );
+        self.pointer = Some(register);
+        Ok(())
+    }
+
+    /// perform several operations in a single bus transaction
+    ///
+    /// Without repeated start, each operation is performed on its own.
+    /// Errors are reported for the given `access` and the register pointer
+    /// is left at the `last` register accessed.
+    async fn transaction(
+        &mut self,
+        operations: &mut [Operation<'_>],
+        access: Access,
+        last: Register,
+    ) -> Result<(), Error<E>> {
+        self.pointer = None;
+        if self.separate_transfers {
+            for operation in operations.iter_mut() {
+                match operation {
+                    Operation::Write(bytes) => self.i2c.write(self.address, bytes).await,
+                    Operation::Read(buffer) => self.i2c.read(self.address, buffer).await,
+                }
+                .map_err(|source| Error::I2C { access, source })?;
+            }
+        } else {
+            self.i2c
+                .transaction(self.address, operations)
+                .await
+                .map_err(|source| Error::I2C { access, source })?;
+        }
+        trace!(
+            "transaction with 0x{:02x}: {:02x?}",
+            self.address,
+            operations
+        );
+        self.pointer = Some(last);
+        Ok(())
+    }
+
+    /// write sample rate period (ms) to the sample rate register
+    async fn write_sample_rate(&mut self, period: u16) -> Result<(), Error<E>> {
+        check_sample_rate(period)?;
+        let byte = conversion::convert_sample_rate_to_register(period);
+        self.write_register(Register::TIdle, &[byte]).await
+    }
+
+    /// Write the contents of a register.
+    ///
+    /// This is a low-level method intended for advanced use, for example
+    /// to access vendor-specific registers of compatible devices.
+    /// Up to 2 data bytes can be written, otherwise
+    /// `Error::InvalidInputData` will be returned.
+    ///
+    /// Note that writing the configuration register through this method
+    /// does not update the locally cached configuration.
+    ///
+    /// If write verification is enabled, the register is read back after
+    /// writing any data within the same bus transaction.
+    pub async fn write_register(
+        &mut self,
+        register: Register,
+        data: &[u8],
+    ) -> Result<(), Error<E>> {
+        if data.len() > 2 {
+            return Err(Error::InvalidInputData);
+        }
+        let mut payload = [register.addr(), 0, 0];
+        payload[1..=data.len()].copy_from_slice(data);
+        let payload = &payload[..=data.len()];
+        if self.verify_writes && !data.is_empty() {
+            let mut read_back = [0; 2];
+            let read_back = &mut read_back[..data.len()];
+            self.transaction(
+                &mut [Operation::Write(payload), Operation::Read(read_back)],
+                Access::Write(register),
+                register,
+            )
+            .await?;
+            if read_back != data {
+                return Err(Error::WriteVerificationFailed);
+            }
+            return Ok(());
+        }
+        self.write_register_unverified(register, payload).await
+    }
+
+    /// write a register without reading it back
+    ///
+    /// The payload includes the register address.
+    async fn write_register_unverified(
+        &mut self,
+        register: Register,
+        payload: &[u8],
+    ) -> Result<(), Error<E>> {
+        self.pointer = None;
+        self.i2c
+            .write(self.address, payload)
+            .await
+            .map_err(|source| Error::I2C {
+                access: Access::Write(register),
+                source,
+            })?;
+        trace!(
+            "write {:?} to 0x{:02x}: {:02x?}",
+            register,
+            self.address,
+            &payload[1..]
+        );
+        self.pointer = Some(register);
+        Ok(())
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), InterruptMode(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, IC, E> Lm75<I2C, IC>
+where
+    I2C: I2c<Error = E>,
+    IC: ResolutionSupport,
+{
+    /// Enable the sensor (default state).
+    pub async fn enable(&mut self) -> Result<(), Error<E>> {
+        let config = self.current_config().await?;
+        self.write_config(config.with_shutdown(false)).await
+    }
+
+    /// Disable the sensor (shutdown).
+    pub async fn disable(&mut self) -> Result<(), Error<E>> {
+        let config = self.current_config().await?;
+        self.write_config(config.with_shutdown(true)).await
+    }
+
+    /// Set the fault queue.
+    ///
+    /// Set the number of consecutive faults that will trigger an OS condition.
+    pub async fn set_fault_queue(&mut self, fq: FaultQueue) -> Result<(), Error<E>> {
+        let config = self.current_config().await?;
+        self.write_config(config.with_fault_queue(fq)).await
+    }
+
+    /// Set the OS polarity.
+    pub async fn set_os_polarity(&mut self, polarity: OsPolarity) -> Result<(), Error<E>> {
+        let config = self.current_config().await?;
+        self.write_config(config.with_os_polarity(polarity)).await
+    }
+
+    /// Set the OS operation mode.
+    pub async fn set_os_mode(&mut self, mode: OsMode) -> Result<(), Error<E>> {
+        let config = self.current_config().await?;
+        self.write_config(config.with_os_mode(mode)).await
+    }
+
+    /// Switch the OS output to interrupt mode.
+    ///
+    /// The returned driver provides the methods specific to interrupt mode.
+    /// See [`InterruptMode`].
+    pub async fn into_interrupt_mode(mut self) -> Result<InterruptMode<I2C, IC>, Error<E>> {
+        self.set_os_mode(OsMode::Interrupt).await?;
+        Ok(InterruptMode { sensor: self })
+    }
+
+    /// Modify several configuration settings with a single register write.
+    ///
+    /// The closure receives the current configuration and returns the
+    /// configuration to be written.
+    ///
+    /// ```no_run, only_if(sync)
+    /// # use linux_embedded_hal::I2cdev;
+    /// # use lm75::{Lm75, Address, FaultQueue, OsMode, OsPolarity};
+    /// # let dev = I2cdev::new("/dev/i2c-1").unwrap();
+    /// let mut sensor = Lm75::new(dev, Address::default());
+    /// sensor
+    ///     .modify_config(|config| {
+    ///         config
+    ///             .with_fault_queue(FaultQueue::_4)
+    ///             .with_os_polarity(OsPolarity::ActiveHigh)
+    ///             .with_os_mode(OsMode::Interrupt)
+    ///     })
+    ///     .unwrap();
+    /// ```
+    pub async fn modify_config<F>(&mut self, f: F) -> Result<(), Error<E>>
+    where
+        F: FnOnce(Config) -> Config,
+    {
+        let config = self.current_config().await?;
+        self.write_config(f(config)).await
+    }
+
+    /// Set the OS temperature.
+    ///
+    /// The temperature can be given in any format implementing
+    /// `IntoTemperature`, e.g. `f32` celsius, `Celsius` or `Millicelsius`.
+    /// It is rounded to the device resolution as configured with
+    /// `with_rounding()`, truncating towards zero by default, and the
+    /// temperature actually written is returned. For example, 2.6ºC
+    /// becomes 2.5ºC with 9-bit resolution.
+    ///
+    /// Temperatures outside of the operating range of the device, usually
+    /// `[-55 - 125]`, before or after rounding return `Error::OutOfRange`.
+    /// NaN and infinite temperatures return `Error::InvalidInputData`.
+    pub async fn set_os_temperature(
+        &mut self,
+        temperature: impl IntoTemperature,
+    ) -> Result<Celsius, Error<E>> {
+        self.write_temperature(Register::TOs, temperature.into_temperature())
+            .await
+    }
+
+    /// Set the hysteresis temperature.
+    ///
+    /// Returns the temperature actually written.
+    /// See `set_os_temperature()` for the accepted formats, rounding and
+    /// valid range.
+    pub async fn set_hysteresis_temperature(
+        &mut self,
+        temperature: impl IntoTemperature,
+    ) -> Result<Celsius, Error<E>> {
+        self.write_temperature(Register::THyst, temperature.into_temperature())
+            .await
+    }
+
+    /// Set the OS temperature (fahrenheit).
+    ///
+    /// Returns the temperature actually written (fahrenheit).
+    /// See `set_os_temperature()` for the rounding and valid range.
+    pub async fn set_os_temperature_f(&mut self, temperature: f32) -> Result<f32, Error<E>> {
+        let written = self
+            .set_os_temperature(conversion::convert_fahrenheit_to_celsius(temperature))
+            .await?;
+        Ok(written.to_fahrenheit())
+    }
+
+    /// Set the hysteresis temperature (fahrenheit).
+    ///
+    /// Returns the temperature actually written (fahrenheit).
+    /// See `set_os_temperature()` for the rounding and valid range.
+    pub async fn set_hysteresis_temperature_f(
+        &mut self,
+        temperature: f32,
+    ) -> Result<f32, Error<E>> {
+        let written = self
+            .set_hysteresis_temperature(conversion::convert_fahrenheit_to_celsius(temperature))
+            .await?;
+        Ok(written.to_fahrenheit())
+    }
+
+    /// Set the OS temperature (kelvin).
+    ///
+    /// Returns the temperature actually written (kelvin).
+    /// See `set_os_temperature()` for the rounding and valid range.
+    pub async fn set_os_temperature_k(&mut self, temperature: f32) -> Result<f32, Error<E>> {
+        let written = self
+            .set_os_temperature(conversion::convert_kelvin_to_celsius(temperature))
+            .await?;
+        Ok(written.to_kelvin())
+    }
+
+    /// Set the hysteresis temperature (kelvin).
+    ///
+    /// Returns the temperature actually written (kelvin).
+    /// See `set_os_temperature()` for the rounding and valid range.
+    pub async fn set_hysteresis_temperature_k(
+        &mut self,
+        temperature: f32,
+    ) -> Result<f32, Error<E>> {
+        let written = self
+            .set_hysteresis_temperature(conversion::convert_kelvin_to_celsius(temperature))
+            .await?;
+        Ok(written.to_kelvin())
+    }
+
+    /// Set the OS temperature in millidegrees celsius.
+    ///
+    /// This does not use any floating-point math. Returns the temperature
+    /// actually written truncated to whole millidegrees like in
+    /// `read_temperature_mc()`.
+    /// See `set_os_temperature()` for the rounding and valid range.
+    pub async fn set_os_temperature_mc(&mut self, temperature: i32) -> Result<i32, Error<E>> {
+        self.write_temperature_mc(Register::TOs, temperature).await
+    }
+
+    /// Set the hysteresis temperature in millidegrees celsius.
+    ///
+    /// See `set_os_temperature_mc()`.
+    pub async fn set_hysteresis_temperature_mc(
+        &mut self,
+        temperature: i32,
+    ) -> Result<i32, Error<E>> {
+        self.write_temperature_mc(Register::THyst, temperature)
+            .await
+    }
+
+    /// Set the OS temperature as a fixed-point number (celsius).
+    ///
+    /// This does not use any floating-point math. Values that cannot be
+    /// represented as `I16F16` return `Error::OutOfRange`.
+    /// Returns the temperature actually written.
+    /// See `set_os_temperature()` for the rounding and valid range.
+    /// This requires the `fixed` feature.
+    #[cfg(feature = "fixed")]
+    pub async fn set_os_temperature_fixed(
+        &mut self,
+        temperature: impl ToFixed,
+    ) -> Result<I16F16, Error<E>> {
+        self.write_temperature_fixed(Register::TOs, temperature)
+            .await
+    }
+
+    /// Set the hysteresis temperature as a fixed-point number (celsius).
+    ///
+    /// See `set_os_temperature_fixed()`.
+    /// This requires the `fixed` feature.
+    #[cfg(feature = "fixed")]
+    pub async fn set_hysteresis_temperature_fixed(
+        &mut self,
+        temperature: impl ToFixed,
+    ) -> Result<I16F16, Error<E>> {
+        self.write_temperature_fixed(Register::THyst, temperature)
+            .await
+    }
+
+    /// Set the OS and hysteresis temperatures together.
+    ///
+    /// The hysteresis temperature must be lower than the OS temperature
+    /// once converted to the device resolution, otherwise
+    /// `Error::InvalidInputData` is returned without modifying the device.
+    /// Temperatures outside of the operating range of the device return
+    /// `Error::OutOfRange`.
+    ///
+    /// Returns the OS and hysteresis temperatures actually written.
+    /// See `set_os_temperature()`.
+    pub async fn set_os_window(
+        &mut self,
+        os: impl IntoTemperature,
+        hysteresis: impl IntoTemperature,
+    ) -> Result<(Celsius, Celsius), Error<E>> {
+        let os = self.convert_temperature(os.into_temperature())?;
+        let hysteresis = self.convert_temperature(hysteresis.into_temperature())?;
+        if hysteresis >= os {
+            return Err(Error::InvalidInputData);
+        }
+        let hysteresis = self
+            .write_temperature_raw(Register::THyst, hysteresis)
+            .await?;
+        let os = self.write_temperature_raw(Register::TOs, os).await?;
+        Ok((os, hysteresis))
+    }
+
+    /// Configure the OS (alert) output.
+    ///
+    /// Sets the OS and hysteresis temperatures, fault queue, OS polarity
+    /// and OS operation mode in one call. The temperatures are validated
+    /// like in `set_os_window()` before writing anything. The temperature
+    /// registers are written first and the configuration register is only
+    /// written if it changes.
+    ///
+    /// Returns the OS and hysteresis temperatures actually written.
+    ///
+    /// ```no_run, only_if(sync)
+    /// # use linux_embedded_hal::I2cdev;
+    /// # use lm75::{Address, AlertConfig, Lm75, OsMode};
+    /// # let dev = I2cdev::new("/dev/i2c-1").unwrap();
+    /// let mut sensor = Lm75::new(dev, Address::default());
+    /// sensor
+    ///     .configure_alert(&AlertConfig {
+    ///         os_temperature: 60.0,
+    ///         hysteresis_temperature: 55.0,
+    ///         os_mode: OsMode::Interrupt,
+    ///         ..AlertConfig::default()
+    ///     })
+    ///     .unwrap();
+    /// ```
+    pub async fn configure_alert(
+        &mut self,
+        alert: &AlertConfig,
+    ) -> Result<(Celsius, Celsius), Error<E>> {
+        let (os, hysteresis) = self
+            .set_os_window(alert.os_temperature, alert.hysteresis_temperature)
+            .await?;
+        let current = self.current_config().await?;
+        let config = current
+            .with_fault_queue(alert.fault_queue)
+            .with_os_polarity(alert.os_polarity)
+            .with_os_mode(alert.os_mode);
+        if config != current {
+            self.write_config(config).await?;
+        }
+        Ok((os, hysteresis))
+    }
+
+    /// Set the OS temperature in raw register units.
+    ///
+    /// The value has the same format as returned by `read_temperature_raw()`,
+    /// i.e. the temperature in celsius multiplied by 256. The value is
+    /// rounded to the device resolution like in `set_os_temperature()`
+    /// and the value actually written is returned.
+    pub async fn set_os_temperature_raw(&mut self, temperature: i16) -> Result<i16, Error<E>> {
+        self.write_temperature_as_raw(Register::TOs, Temperature::Raw(temperature))
+            .await
+    }
+
+    /// Set the hysteresis temperature in raw register units.
+    ///
+    /// The value has the same format as returned by `read_temperature_raw()`,
+    /// i.e. the temperature in celsius multiplied by 256. The value is
+    /// rounded to the device resolution like in `set_os_temperature()`
+    /// and the value actually written is returned.
+    pub async fn set_hysteresis_temperature_raw(
+        &mut self,
+        temperature: i16,
+    ) -> Result<i16, Error<E>> {
+        self.write_temperature_as_raw(Register::THyst, Temperature::Raw(temperature))
+            .await
+    }
+
+    /// Read the temperature from the sensor (celsius).
+    ///
+    /// If the device is in shutdown and no one-shot conversion was started
+    /// since the last reading, `Error::Shutdown` is returned instead of the
+    /// stale reading.
+    /// `read_measurement()` can be used to read it anyway.
+    pub async fn read_temperature(&mut self) -> Result<f32, Error<E>> {
+        self.check_fresh_reading()?;
+        let mut data = [0; 2];
+        self.read_register(Register::Temperature, &mut data).await?;
+        self.one_shot_started = false;
+        self.decode_temperature(data, self.config)
+    }
+
+    /// Read the temperature from the sensor.
+    ///
+    /// This is the same as `read_temperature()` returning a [`Celsius`] value.
+    pub async fn read_celsius(&mut self) -> Result<Celsius, Error<E>> {
+        self.read_temperature().await.map(Celsius)
+    }
+
+    /// Read the temperature from the sensor in millidegrees celsius.
+    ///
+    /// This does not use any floating-point math. The temperature is
+    /// truncated to whole millidegrees, e.g. 25.0625ºC is 25062.
+    /// See `read_temperature()`.
+    pub async fn read_temperature_mc(&mut self) -> Result<i32, Error<E>> {
+        let raw = self.read_temperature_raw().await?;
+        let offset = i32::from(self.ic.get_temperature_offset(self.config));
+        Ok(conversion::convert_temp_raw_to_millicelsius(raw) + offset * 1000)
+    }
+
+    /// Read the temperature from the sensor as a fixed-point number (celsius).
+    ///
+    /// This does not use any floating-point math, e.g.
+    /// `read_temperature_fixed::<I9F7>()`. If the temperature cannot be
+    /// represented by `T`, `Error::OutOfRange` is returned.
+    /// See `read_temperature()`.
+    /// This requires the `fixed` feature.
+    #[cfg(feature = "fixed")]
+    pub async fn read_temperature_fixed<T: FromFixed>(&mut self) -> Result<T, Error<E>> {
+        let raw = self.read_temperature_raw().await?;
+        let offset = i32::from(self.ic.get_temperature_offset(self.config));
+        // I16F16 has 16 fractional bits and the raw value 8
+        let temperature = I16F16::from_bits((i32::from(raw) + offset * 256) * 256);
+        T::checked_from_fixed(temperature).ok_or(Error::OutOfRange)
+    }
+
+    /// Read the temperature from the sensor as a `uom` quantity.
+    ///
+    /// See `read_temperature()`.
+    /// This requires the `uom` feature.
+    #[cfg(feature = "uom")]
+    pub async fn read_thermodynamic_temperature(
+        &mut self,
+    ) -> Result<ThermodynamicTemperature, Error<E>> {
+        self.read_celsius().await.map(Into::into)
+    }
+
+    /// Read the temperature from the sensor as `f64` (celsius).
+    ///
+    /// The register value is converted directly to `f64`. The setters accept
+    /// `f64` temperatures as well.
+    /// See `read_temperature()`.
+    pub async fn read_temperature_f64(&mut self) -> Result<f64, Error<E>> {
+        let raw = self.read_temperature_raw().await?;
+        let offset = self.ic.get_temperature_offset(self.config);
+        Ok(f64::from(raw) / 256.0 + f64::from(offset))
+    }
+
+    /// Read the temperature from the sensor (fahrenheit).
+    ///
+    /// See `read_temperature()`.
+    pub async fn read_temperature_f(&mut self) -> Result<f32, Error<E>> {
+        let temperature = self.read_temperature().await?;
+        Ok(conversion::convert_celsius_to_fahrenheit(temperature))
+    }
+
+    /// Read the temperature from the sensor (kelvin).
+    ///
+    /// See `read_temperature()`.
+    pub async fn read_temperature_k(&mut self) -> Result<f32, Error<E>> {
+        let temperature = self.read_temperature().await?;
+        Ok(conversion::convert_celsius_to_kelvin(temperature))
+    }
+
+    /// Read the raw temperature register value.
+    ///
+    /// The value is left-justified in two's complement format with the bits
+    /// below the device resolution cleared, i.e. the temperature in celsius
+    /// is the returned value divided by 256. For example, `0x1980` is 25.5ºC.
+    ///
+    /// This allows using the temperature without any floating-point math.
+    /// `RawTemperature` converts it from and into other formats.
+    /// For the MAX31725/6 in the extended data format, the value is the
+    /// temperature minus 64ºC.
+    ///
+    /// `Error::Shutdown` is returned like for `read_temperature()`.
+    pub async fn read_temperature_raw(&mut self) -> Result<i16, Error<E>> {
+        self.check_fresh_reading()?;
+        let mut data = [0; 2];
+        self.read_register(Register::Temperature, &mut data).await?;
+        self.one_shot_started = false;
+        self.check_temperature_reading(data, self.config)
+    }
+
+    /// Read the temperature from the sensor (celsius) in non-blocking steps.
+    ///
+    /// The first call may only set the register pointer and return
+    /// `nb::Error::WouldBlock`. See `try_read_register()` for details.
+    /// `Error::Shutdown` is returned like for `read_temperature()`.
+    ///
+    /// ```no_run
+    /// # use linux_embedded_hal::I2cdev;
+    /// # use lm75::{Lm75, Address};
+    /// # let dev = I2cdev::new("/dev/i2c-1").unwrap();
+    /// let mut sensor = Lm75::new(dev, Address::default());
+    /// let temp_celsius = nb::block!(sensor.try_read_temperature()).unwrap();
+    /// ```
+    #[maybe_async_cfg::only_if(sync)]
+    pub fn try_read_temperature(&mut self) -> nb::Result<f32, Error<E>> {
+        self.check_fresh_reading()?;
+        let mut data = [0; 2];
+        self.try_read_register(Register::Temperature, &mut data)?;
+        self.one_shot_started = false;
+        Ok(self.decode_temperature(data, self.config)?)
+    }
+
+    /// Read the raw temperature register value in non-blocking steps.
+    ///
+    /// See `read_temperature_raw()` for the format of the value and
+    /// `try_read_register()` for details about the steps.
+    /// `Error::Shutdown` is returned like for `read_temperature()`.
+    #[maybe_async_cfg::only_if(sync)]
+    pub fn try_read_temperature_raw(&mut self) -> nb::Result<i16, Error<E>> {
+        self.check_fresh_reading()?;
+        let mut data = [0; 2];
+        self.try_read_register(Register::Temperature, &mut data)?;
+        self.one_shot_started = false;
+        Ok(self.check_temperature_reading(data, self.config)?)
+    }
+
+    /// Get a stream of temperature readings (celsius).
+    ///
+    /// The first temperature is read immediately and then one every
+    /// `period_ms` milliseconds using the provided delay.
+    /// For a PCT2075 the period can be set to the configured sample rate
+    /// to get every new conversion.
+    ///
+    /// ```
+    /// # use embedded_hal_async::{delay::DelayNs, i2c::I2c};
+    /// use futures_util::{pin_mut, StreamExt};
+    /// use lm75::{Address, Lm75Async};
+    ///
+    /// async fn log_temperature<I2C: I2c, D: DelayNs>(i2c: I2C, delay: D) {
+    ///     let mut sensor = Lm75Async::new(i2c, Address::default());
+    ///     let readings = sensor.temperature_stream(delay, 1000);
+    ///     pin_mut!(readings);
+    ///     while let Some(Ok(temp_celsius)) = readings.next().await {
+    ///         // ...
+    ///     }
+    /// }
+    /// ```
+    #[maybe_async_cfg::only_if(async)]
+    pub fn temperature_stream<'a, D: AsyncDelayNs + 'a>(
+        &'a mut self,
+        delay: D,
+        period_ms: u32,
+    ) -> impl Stream<Item = Result<f32, Error<E>>> + 'a {
+        stream::unfold(
+            (self, delay, true),
+            move |(sensor, mut delay, first)| async move {
+                if !first {
+                    delay.delay_ms(period_ms).await;
+                }
+                let temperature = sensor.read_temperature().await;
+                Some((temperature, (sensor, delay, false)))
+            },
+        )
+    }
+
+    /// Get the temperature resolution of the device (celsius).
+    ///
+    /// This is the temperature step corresponding to the least significant
+    /// bit of the temperature register, e.g. 0.5ºC for the LM75 and
+    /// 0.125ºC for the PCT2075. For devices with configurable resolution
+    /// this corresponds to the current configuration.
+    pub fn resolution(&self) -> f32 {
+        conversion::convert_resolution_from_mask(self.ic.get_resolution_mask(self.config))
+    }
+
+    /// Read the temperature together with the device shutdown state.
+    ///
+    /// This reads the temperature and the configuration register in a single
+    /// bus transaction so that a stale temperature from a device in shutdown
+    /// can be told apart from a fresh one.
+    pub async fn read_measurement(&mut self) -> Result<Measurement, Error<E>> {
+        let pointers = [Register::Temperature, Register::Configuration].map(|r| [r.addr()]);
+        let mut temperature = [0; 2];
+        let mut config = [0];
+        self.transaction(
+            &mut [
+                Operation::Write(&pointers[0]),
+                Operation::Read(&mut temperature),
+                Operation::Write(&pointers[1]),
+                Operation::Read(&mut config),
+            ],
+            Access::ReadRegisters,
+            Register::Configuration,
+        )
+        .await?;
+        let config = Config { bits: config[0] };
+        Ok(Measurement {
+            temperature: self.decode_temperature(temperature, config)?,
+            shutdown: config.is_shutdown(),
+            resolution: conversion::convert_resolution_from_mask(
+                self.ic.get_resolution_mask(config),
+            ),
+        })
+    }
+
+    /// decode a temperature register value for the given configuration
+    fn decode_temperature(&self, data: [u8; 2], config: Config) -> Result<f32, Error<E>> {
+        let mask = self.ic.get_resolution_mask(config);
+        let offset = self.ic.get_temperature_offset(config);
+        self.check_temperature_reading(data, config)?;
+        let temperature = conversion::convert_temp_from_register(data[0], data[1], mask);
+        Ok(temperature + f32::from(offset))
+    }
+
+    /// get the raw value of a temperature register reading
+    ///
+    /// If enabled, check that the reading is within the operating range.
+    /// The offset (celsius) of the register encoding is taken into account.
+    fn check_temperature_reading(&self, data: [u8; 2], config: Config) -> Result<i16, Error<E>> {
+        let mask = self.ic.get_resolution_mask(config);
+        let raw = conversion::convert_temp_raw_from_register(data[0], data[1], mask);
+        let offset = i32::from(self.ic.get_temperature_offset(config)) * 256;
+        let range = self.ic.get_temperature_range(config);
+        if self.check_readings && !is_valid_temperature_raw(i32::from(raw) + offset, range) {
+            Err(Error::SensorFault)
+        } else {
+            Ok(raw)
+        }
+    }
+
+    /// check that a temperature (celsius) can be set as limit
+    fn check_temperature(&self, temperature: f32) -> Result<(), Error<E>> {
+        check_temperature(
+            temperature,
+            self.ic.get_temperature_range(self.config),
+            self.ic.get_temperature_offset(self.config),
+        )
+    }
+
+    /// check that the temperature register holds a conversion that is not stale
+    fn check_fresh_reading(&self) -> Result<(), Error<E>> {
+        if self.config.is_shutdown() && !self.one_shot_started {
+            Err(Error::Shutdown)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// get the configuration to be modified
+    async fn current_config(&mut self) -> Result<Config, Error<E>> {
+        match self.config_update {
+            ConfigUpdate::Cached => Ok(self.config),
+            ConfigUpdate::ReadModifyWrite => self.read_config().await,
+        }
+    }
+
+    /// convert a temperature to the raw limit register value
+    fn convert_temperature(&self, temperature: Temperature) -> Result<i16, Error<E>> {
+        let offset = i64::from(self.ic.get_temperature_offset(self.config));
+        match temperature {
+            Temperature::Celsius(temperature) => {
+                self.check_temperature(temperature)?;
+                let raw = conversion::convert_temp_to_raw(
+                    temperature - offset as f32,
+                    self.ic.get_limit_mask(self.config),
+                    self.rounding,
+                );
+                self.check_rounded_temperature_raw(raw)
+            }
+            Temperature::Millicelsius(temperature) => {
+                self.round_temperature_raw((i64::from(temperature) - offset * 1000) * 256, 1000)
+            }
+            Temperature::Raw(raw) => self.round_temperature_raw(i64::from(raw), 1),
+        }
+    }
+
+    /// check and round a raw limit register value given as a fraction
+    fn round_temperature_raw(&self, numerator: i64, denominator: i64) -> Result<i16, Error<E>> {
+        let offset = i64::from(self.ic.get_temperature_offset(self.config));
+        let (min, max) = self.ic.get_temperature_range(self.config);
+        let temperature = numerator + offset * 256 * denominator;
+        if temperature < i64::from(min) * 256 * denominator
+            || temperature > i64::from(max) * 256 * denominator
+        {
+            return Err(Error::OutOfRange);
+        }
+        let raw = conversion::round_temp_raw(
+            numerator,
+            denominator,
+            self.ic.get_limit_mask(self.config),
+            self.rounding,
+        );
+        self.check_rounded_temperature_raw(raw)
+    }
+
+    /// check that a rounded limit register value is still within the
+    /// operating range, since rounding to nearest can exceed it
+    fn check_rounded_temperature_raw(&self, raw: i64) -> Result<i16, Error<E>> {
+        let offset = i64::from(self.ic.get_temperature_offset(self.config));
+        let (min, max) = self.ic.get_temperature_range(self.config);
+        let temperature = raw + offset * 256;
+        if temperature < i64::from(min) * 256 || temperature > i64::from(max) * 256 {
+            return Err(Error::OutOfRange);
+        }
+        i16::try_from(raw).map_err(|_| Error::OutOfRange)
+    }
+
+    /// write temperature to a temperature register and return the value written
+    async fn write_temperature(
+        &mut self,
+        register: Register,
+        temperature: Temperature,
+    ) -> Result<Celsius, Error<E>> {
+        let raw = self.convert_temperature(temperature)?;
+        self.write_temperature_raw(register, raw).await
+    }
+
+    /// write temperature to a temperature register and return the raw value written
+    async fn write_temperature_as_raw(
+        &mut self,
+        register: Register,
+        temperature: Temperature,
+    ) -> Result<i16, Error<E>> {
+        let raw = self.convert_temperature(temperature)?;
+        self.write_temperature_raw(register, raw).await?;
+        Ok(raw)
+    }
+
+    /// write millicelsius to a temperature register and return the value written
+    async fn write_temperature_mc(
+        &mut self,
+        register: Register,
+        temperature: i32,
+    ) -> Result<i32, Error<E>> {
+        let raw = self
+            .write_temperature_as_raw(register, Temperature::Millicelsius(temperature))
+            .await?;
+        let offset = i32::from(self.ic.get_temperature_offset(self.config));
+        Ok(conversion::convert_temp_raw_to_millicelsius(raw) + offset * 1000)
+    }
+
+    /// write raw temperature to a temperature register and return the value written
+    async fn write_temperature_raw(
+        &mut self,
+        register: Register,
+        raw: i16,
+    ) -> Result<Celsius, Error<E>> {
+        let (msb, lsb) =
+            conversion::convert_temp_raw_to_register(raw, self.ic.get_limit_mask(self.config));
+        self.write_register(register, &[msb, lsb]).await?;
+        let offset = self.ic.get_temperature_offset(self.config);
+        Ok(Celsius(f32::from(raw) / 256.0 + f32::from(offset)))
+    }
+
+    /// write a fixed-point temperature (celsius) to a temperature register
+    #[cfg(feature = "fixed")]
+    async fn write_temperature_fixed(
+        &mut self,
+        register: Register,
+        temperature: impl ToFixed,
+    ) -> Result<I16F16, Error<E>> {
+        let temperature = I16F16::checked_from_num(temperature).ok_or(Error::OutOfRange)?;
+        let offset = i32::from(self.ic.get_temperature_offset(self.config));
+        // I16F16 has 16 fractional bits and the raw value 8
+        let raw = self.round_temperature_raw(
+            i64::from(temperature.to_bits()) - i64::from(offset) * 65536,
+            256,
+        )?;
+        self.write_temperature_raw(register, raw).await?;
+        Ok(I16F16::from_bits((i32::from(raw) + offset * 256) * 256))
+    }
+
+    /// read configuration from device
+    async fn read_config(&mut self) -> Result<Config, Error<E>> {
+        let mut data = [0; 1];
+        self.read_register(Register::Configuration, &mut data)
+            .await?;
+        Ok(Config { bits: data[0] })
+    }
+
+    /// write configuration to device
+    async fn write_config(&mut self, config: Config) -> Result<(), Error<E>> {
+        self.write_register(Register::Configuration, &[config.bits])
+            .await?;
+        if config.is_shutdown() && !self.config.is_shutdown() {
+            self.one_shot_started = false;
+        }
+        self.config = config;
+        Ok(())
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, IC, E> Lm75<I2C, IC>
+where
+    I2C: I2c<Error = E>,
+    IC: ResolutionSupport + RegisterSupport,
+{
+    /// Describe the capabilities of the device.
+    ///
+    /// The resolution and temperature range correspond to the cached
+    /// configuration.
+    pub fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            resolution_bits: self.ic.get_resolution_mask(self.config).count_ones() as u8,
+            has_sample_rate: self.ic.has_sample_rate_register(),
+            has_one_shot: self.ic.has_one_shot(),
+            temperature_range: self.ic.get_temperature_range(self.config),
+        }
+    }
+
+    /// Estimate the average supply current of the device (µA).
+    ///
+    /// With `period_ms`, this estimates sampling every `period_ms`
+    /// milliseconds with the device in shutdown in between like with
+    /// `with_duty_cycle()`. The device is then assumed to be in continuous
+    /// operation for the same awake time as `with_duty_cycle()`: the
+    /// conversion time at the configured resolution plus, for the PCT2075,
+    /// the configured sample rate. Otherwise, this estimates continuous
+    /// operation.
+    ///
+    /// For the PCT2075, the continuous operation current is scaled to the
+    /// configured sample rate, which is read from the device.
+    ///
+    /// The estimation is based on the typical datasheet figures of the
+    /// device and does not include the I²C bus activity.
+    pub async fn estimate_supply_current_ua(
+        &mut self,
+        period_ms: Option<u32>,
+    ) -> Result<f32, Error<E>> {
+        let (mut operating_ua, shutdown_ua) = self.ic.get_supply_current_ua();
+        let awake_ms = self.read_awake_time_ms().await?;
+        if self.ic.has_sample_rate_register() {
+            // The figure corresponds to the default sample rate of 100ms and
+            // the device idles at the shutdown current between conversions.
+            let default_awake_ms = self.ic.get_conversion_time_ms(self.config) + 100;
+            operating_ua = shutdown_ua
+                + (operating_ua - shutdown_ua) * default_awake_ms as f32 / awake_ms as f32;
+        }
+        match period_ms {
+            Some(period_ms) => {
+                let duty_cycle = if period_ms > awake_ms {
+                    awake_ms as f32 / period_ms as f32
+                } else {
+                    1.0
+                };
+                Ok(duty_cycle * operating_ua + (1.0 - duty_cycle) * shutdown_ua)
+            }
+            None => Ok(operating_ua),
+        }
+    }
+
+    /// time the device is awake for a conversion (ms), including the idle
+    /// time of the configured sample rate if available
+    async fn read_awake_time_ms(&mut self) -> Result<u32, Error<E>> {
+        let mut awake_ms = self.ic.get_conversion_time_ms(self.config);
+        if self.ic.has_sample_rate_register() {
+            let mut data = [0; 1];
+            self.read_register(Register::TIdle, &mut data).await?;
+            awake_ms += u32::from(conversion::convert_sample_rate_from_register(data[0]));
+        }
+        Ok(awake_ms)
+    }
+
+    /// Program the power-on default values into the device.
+    ///
+    /// These are: device enabled, fault queue 1, OS active-low in
+    /// comparator mode, TOS=80ºC, THYST=75ºC and a sample rate period of
+    /// 100ms where supported. The cached configuration is reset as well.
+    pub async fn reset_to_defaults(&mut self) -> Result<(), Error<E>> {
+        let defaults = Settings::default();
+        if self.ic.has_sample_rate_register() {
+            self.write_sample_rate(100).await?;
+        }
+        // The limits are encoded with the default configuration, which for
+        // example disables the MAX31725/6 extended data format.
+        self.write_config(Config::default()).await?;
+        self.write_temperature(
+            Register::THyst,
+            Temperature::Celsius(defaults.hysteresis_temperature),
+        )
+        .await?;
+        self.write_temperature(Register::TOs, Temperature::Celsius(defaults.os_temperature))
+            .await?;
+        Ok(())
+    }
+
+    /// Read the configured registers of the device in a single bus transaction.
+    ///
+    /// The returned state can be programmed back with `restore_state()`,
+    /// for example after a power cycle.
+    pub async fn save_state(&mut self) -> Result<DeviceState, Error<E>> {
+        let pointers = [
+            Register::Configuration,
+            Register::THyst,
+            Register::TOs,
+            Register::TIdle,
+        ]
+        .map(|r| [r.addr()]);
+        let mut state = DeviceState::default();
+        let mut config = [0];
+        let mut t_idle = [0];
+        let (count, last) = if self.ic.has_sample_rate_register() {
+            (8, Register::TIdle)
+        } else {
+            (6, Register::TOs)
+        };
+        self.transaction(
+            &mut [
+                Operation::Write(&pointers[0]),
+                Operation::Read(&mut config),
+                Operation::Write(&pointers[1]),
+                Operation::Read(&mut state.t_hyst),
+                Operation::Write(&pointers[2]),
+                Operation::Read(&mut state.t_os),
+                Operation::Write(&pointers[3]),
+                Operation::Read(&mut t_idle),
+            ][..count],
+            Access::ReadRegisters,
+            last,
+        )
+        .await?;
+        state.config = Config { bits: config[0] };
+        if self.ic.has_sample_rate_register() {
+            state.t_idle = Some(t_idle[0]);
+        }
+        Ok(state)
+    }
+
+    /// Program the device registers from a state obtained with `save_state()`.
+    ///
+    /// The configuration register is written last. If the state contains a
+    /// sample period but the device does not support it,
+    /// `Error::InvalidInputData` is returned without modifying the device.
+    pub async fn restore_state(&mut self, state: &DeviceState) -> Result<(), Error<E>> {
+        if let Some(t_idle) = state.t_idle {
+            if !self.ic.has_sample_rate_register() {
+                return Err(Error::InvalidInputData);
+            }
+            self.write_register(Register::TIdle, &[t_idle]).await?;
+        }
+        self.write_register(Register::THyst, &state.t_hyst).await?;
+        self.write_register(Register::TOs, &state.t_os).await?;
+        self.write_config(state.config).await
+    }
+
+    /// Apply the complete device settings.
+    ///
+    /// This writes each register only once. All settings are validated
+    /// before writing anything so that an error is returned without
+    /// modifying the device if any value is invalid.
+    /// The configuration register is written last.
+    pub async fn apply_settings(&mut self, settings: &Settings) -> Result<(), Error<E>> {
+        self.check_temperature(settings.os_temperature)?;
+        self.check_temperature(settings.hysteresis_temperature)?;
+        if let Some(period) = settings.sample_rate {
+            if !self.ic.has_sample_rate_register() {
+                return Err(Error::InvalidInputData);
+            }
+            check_sample_rate(period)?;
+            self.write_sample_rate(period).await?;
+        }
+        self.write_temperature(
+            Register::THyst,
+            Temperature::Celsius(settings.hysteresis_temperature),
+        )
+        .await?;
+        self.write_temperature(Register::TOs, Temperature::Celsius(settings.os_temperature))
+            .await?;
+        let config = self
+            .current_config()
+            .await?
+            .with_shutdown(settings.shutdown)
+            .with_fault_queue(settings.fault_queue)
+            .with_os_polarity(settings.os_polarity)
+            .with_os_mode(settings.os_mode);
+        self.write_config(config).await
+    }
+
+    /// Read the raw contents of all known registers.
+    ///
+    /// All registers are read in a single bus transaction.
+    /// This is intended for debugging purposes.
+    pub async fn dump_registers(&mut self) -> Result<RegisterDump, Error<E>> {
+        let pointers = [
+            Register::Temperature,
+            Register::Configuration,
+            Register::THyst,
+            Register::TOs,
+            Register::TIdle,
+        ]
+        .map(|r| [r.addr()]);
+        let mut dump = RegisterDump::default();
+        let mut configuration = [0];
+        let mut t_idle = [0];
+        let (count, last) = if self.ic.has_sample_rate_register() {
+            (10, Register::TIdle)
+        } else {
+            (8, Register::TOs)
+        };
+        self.transaction(
+            &mut [
+                Operation::Write(&pointers[0]),
+                Operation::Read(&mut dump.temperature),
+                Operation::Write(&pointers[1]),
+                Operation::Read(&mut configuration),
+                Operation::Write(&pointers[2]),
+                Operation::Read(&mut dump.t_hyst),
+                Operation::Write(&pointers[3]),
+                Operation::Read(&mut dump.t_os),
+                Operation::Write(&pointers[4]),
+                Operation::Read(&mut t_idle),
+            ][..count],
+            Access::ReadRegisters,
+            last,
+        )
+        .await?;
+        dump.configuration = configuration[0];
+        if self.ic.has_sample_rate_register() {
+            dump.t_idle = Some(t_idle[0]);
+        }
+        Ok(dump)
+    }
+}
+
+#[cfg(feature = "pct2075")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, E> Lm75<I2C, ic::Pct2075>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create new instance of the PCT2075 device.
+    pub fn new_pct2075<A: Into<Address>>(i2c: I2C, address: A) -> Self {
+        Self::create(i2c, address.into(), ic::Pct2075)
+    }
+
+    /// Create new instance of the PCT2075 device reading the current
+    /// configuration from the device instead of assuming power-on defaults.
+    ///
+    /// This is useful when the device may have been configured before,
+    /// for example if the MCU was reset but the sensor kept its power.
+    pub async fn new_pct2075_synced<A: Into<Address>>(
+        i2c: I2C,
+        address: A,
+    ) -> Result<Self, Error<E>> {
+        let mut sensor = Self::new_pct2075(i2c, address);
+        sensor.config = sensor.read_config().await?;
+        Ok(sensor)
+    }
+}
+
+#[cfg(feature = "lm75a")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, E> Lm75<I2C, ic::Lm75a>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create new instance of the NXP LM75A/LM75B device.
+    ///
+    /// The temperature is read with the full 11-bit resolution of these
+    /// devices.
+    pub fn new_lm75a<A: Into<Address>>(i2c: I2C, address: A) -> Self {
+        Self::create(i2c, address.into(), ic::Lm75a)
+    }
+
+    /// Create new instance of the NXP LM75A/LM75B device reading the
+    /// current configuration from the device instead of assuming power-on
+    /// defaults.
+    ///
+    /// This is useful when the device may have been configured before,
+    /// for example if the MCU was reset but the sensor kept its power.
+    pub async fn new_lm75a_synced<A: Into<Address>>(
+        i2c: I2C,
+        address: A,
+    ) -> Result<Self, Error<E>> {
+        let mut sensor = Self::new_lm75a(i2c, address);
+        sensor.config = sensor.read_config().await?;
+        Ok(sensor)
+    }
+}
+
+#[cfg(feature = "g751")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, E> Lm75<I2C, ic::G751>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create new instance of the G751 device.
+    pub fn new_g751<A: Into<Address>>(i2c: I2C, address: A) -> Self {
+        Self::create(i2c, address.into(), ic::G751)
+    }
+
+    /// Create new instance of the G751 device reading the current
+    /// configuration from the device instead of assuming power-on defaults.
+    ///
+    /// This is useful when the device may have been configured before,
+    /// for example if the MCU was reset but the sensor kept its power.
+    pub async fn new_g751_synced<A: Into<Address>>(i2c: I2C, address: A) -> Result<Self, Error<E>> {
+        let mut sensor = Self::new_g751(i2c, address);
+        sensor.config = sensor.read_config().await?;
+        Ok(sensor)
+    }
+}
+
+#[cfg(feature = "mcp980x")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, E> Lm75<I2C, ic::Mcp980x>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create new instance of the MCP9800/1/2/3 device.
+    pub fn new_mcp980x<A: Into<Address>>(i2c: I2C, address: A) -> Self {
+        Self::create(i2c, address.into(), ic::Mcp980x)
+    }
+
+    /// Create new instance of the MCP9800/1/2/3 device reading the current
+    /// configuration from the device instead of assuming power-on defaults.
+    ///
+    /// This is useful when the device may have been configured before,
+    /// for example if the MCU was reset but the sensor kept its power.
+    /// The temperature is then decoded with the configured resolution.
+    pub async fn new_mcp980x_synced<A: Into<Address>>(
+        i2c: I2C,
+        address: A,
+    ) -> Result<Self, Error<E>> {
+        let mut sensor = Self::new_mcp980x(i2c, address);
+        sensor.config = sensor.read_config().await?;
+        Ok(sensor)
+    }
+}
+
+#[cfg(feature = "tcn75a")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, E> Lm75<I2C, ic::Tcn75a>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create new instance of the TCN75A device.
+    pub fn new_tcn75a<A: Into<Address>>(i2c: I2C, address: A) -> Self {
+        Self::create(i2c, address.into(), ic::Tcn75a)
+    }
+
+    /// Create new instance of the TCN75A device reading the current
+    /// configuration from the device instead of assuming power-on defaults.
+    ///
+    /// This is useful when the device may have been configured before,
+    /// for example if the MCU was reset but the sensor kept its power.
+    /// The temperature is then decoded with the configured resolution.
+    pub async fn new_tcn75a_synced<A: Into<Address>>(
+        i2c: I2C,
+        address: A,
+    ) -> Result<Self, Error<E>> {
+        let mut sensor = Self::new_tcn75a(i2c, address);
+        sensor.config = sensor.read_config().await?;
+        Ok(sensor)
+    }
+}
+
+#[cfg(feature = "tmp75")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, E> Lm75<I2C, ic::Tmp75>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create new instance of the TMP75/TMP175/TMP275 device.
+    pub fn new_tmp75<A: Into<Address>>(i2c: I2C, address: A) -> Self {
+        Self::create(i2c, address.into(), ic::Tmp75)
+    }
+
+    /// Create new instance of the TMP75/TMP175/TMP275 device reading the
+    /// current configuration from the device instead of assuming power-on
+    /// defaults.
+    ///
+    /// This is useful when the device may have been configured before,
+    /// for example if the MCU was reset but the sensor kept its power.
+    /// The temperature is then decoded with the configured resolution.
+    pub async fn new_tmp75_synced<A: Into<Address>>(
+        i2c: I2C,
+        address: A,
+    ) -> Result<Self, Error<E>> {
+        let mut sensor = Self::new_tmp75(i2c, address);
+        sensor.config = sensor.read_config().await?;
+        Ok(sensor)
+    }
+}
+
+#[cfg(feature = "tmp1075")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, E> Lm75<I2C, ic::Tmp1075>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create new instance of the TMP1075 device.
+    pub fn new_tmp1075<A: Into<Address>>(i2c: I2C, address: A) -> Self {
+        Self::create(i2c, address.into(), ic::Tmp1075)
+    }
+
+    /// Create new instance of the TMP1075 device reading the current
+    /// configuration from the device instead of assuming power-on defaults.
+    ///
+    /// This is useful when the device may have been configured before,
+    /// for example if the MCU was reset but the sensor kept its power.
+    pub async fn new_tmp1075_synced<A: Into<Address>>(
+        i2c: I2C,
+        address: A,
+    ) -> Result<Self, Error<E>> {
+        let mut sensor = Self::new_tmp1075(i2c, address);
+        sensor.config = sensor.read_config().await?;
+        Ok(sensor)
+    }
+
+    /// Set the conversion rate in continuous mode.
+    pub async fn set_conversion_rate(&mut self, rate: ConversionRate) -> Result<(), Error<E>> {
+        let config = self.current_config().await?;
+        let config = match rate {
+            ConversionRate::Ms27_5 => config
+                .with_low(BitFlags::CONVERSION_RATE1)
+                .with_low(BitFlags::CONVERSION_RATE0),
+            ConversionRate::Ms55 => config
+                .with_low(BitFlags::CONVERSION_RATE1)
+                .with_high(BitFlags::CONVERSION_RATE0),
+            ConversionRate::Ms110 => config
+                .with_high(BitFlags::CONVERSION_RATE1)
+                .with_low(BitFlags::CONVERSION_RATE0),
+            ConversionRate::Ms220 => config
+                .with_high(BitFlags::CONVERSION_RATE1)
+                .with_high(BitFlags::CONVERSION_RATE0),
+        };
+        self.write_config(config).await
+    }
+
+    /// Read the device ID.
+    ///
+    /// This is 0x7500 for the TMP1075 and can be used to tell it apart
+    /// from other LM75-compatible devices.
+    pub async fn read_device_id(&mut self) -> Result<u16, Error<E>> {
+        let mut data = [0; 2];
+        self.read_register(DEVICE_ID, &mut data).await?;
+        Ok(u16::from_be_bytes(data))
+    }
+}
+
+#[cfg(feature = "stds75")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, E> Lm75<I2C, ic::Stds75>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create new instance of the STDS75 device.
+    pub fn new_stds75<A: Into<Address>>(i2c: I2C, address: A) -> Self {
+        Self::create(i2c, address.into(), ic::Stds75)
+    }
+
+    /// Create new instance of the STDS75 device reading the current
+    /// configuration from the device instead of assuming power-on defaults.
+    ///
+    /// This is useful when the device may have been configured before,
+    /// for example if the MCU was reset but the sensor kept its power.
+    /// The temperature is then decoded with the configured resolution.
+    pub async fn new_stds75_synced<A: Into<Address>>(
+        i2c: I2C,
+        address: A,
+    ) -> Result<Self, Error<E>> {
+        let mut sensor = Self::new_stds75(i2c, address);
+        sensor.config = sensor.read_config().await?;
+        Ok(sensor)
+    }
+}
+
+#[cfg(feature = "stlm75")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, E> Lm75<I2C, ic::Stlm75>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create new instance of the STLM75 device.
+    pub fn new_stlm75<A: Into<Address>>(i2c: I2C, address: A) -> Self {
+        Self::create(i2c, address.into(), ic::Stlm75)
+    }
+
+    /// Create new instance of the STLM75 device reading the current
+    /// configuration from the device instead of assuming power-on defaults.
+    ///
+    /// This is useful when the device may have been configured before,
+    /// for example if the MCU was reset but the sensor kept its power.
+    pub async fn new_stlm75_synced<A: Into<Address>>(
+        i2c: I2C,
+        address: A,
+    ) -> Result<Self, Error<E>> {
+        let mut sensor = Self::new_stlm75(i2c, address);
+        sensor.config = sensor.read_config().await?;
+        Ok(sensor)
+    }
+}
+
+#[cfg(feature = "se95")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, E> Lm75<I2C, ic::Se95>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create new instance of the SE95 device.
+    pub fn new_se95<A: Into<Address>>(i2c: I2C, address: A) -> Self {
+        Self::create(i2c, address.into(), ic::Se95)
+    }
+
+    /// Create new instance of the SE95 device reading the current
+    /// configuration from the device instead of assuming power-on defaults.
+    ///
+    /// This is useful when the device may have been configured before,
+    /// for example if the MCU was reset but the sensor kept its power.
+    pub async fn new_se95_synced<A: Into<Address>>(i2c: I2C, address: A) -> Result<Self, Error<E>> {
+        let mut sensor = Self::new_se95(i2c, address);
+        sensor.config = sensor.read_config().await?;
+        Ok(sensor)
+    }
+
+    /// Read the manufacturer ID.
+    ///
+    /// This is 0xA1 for the SE95 and can be used to tell it apart
+    /// from other LM75-compatible devices.
+    pub async fn read_manufacturer_id(&mut self) -> Result<u8, Error<E>> {
+        let mut data = [0];
+        self.read_register(MANUFACTURER_ID, &mut data).await?;
+        Ok(data[0])
+    }
+}
+
+#[cfg(feature = "max3172x")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, E> Lm75<I2C, ic::Max3172x>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create new instance of the MAX31725/MAX31726 device.
+    pub fn new_max3172x<A: Into<Address>>(i2c: I2C, address: A) -> Self {
+        Self::create(i2c, address.into(), ic::Max3172x)
+    }
+
+    /// Create new instance of the MAX31725/MAX31726 device reading the
+    /// current configuration from the device instead of assuming power-on
+    /// defaults.
+    ///
+    /// This is useful when the device may have been configured before,
+    /// for example if the MCU was reset but the sensor kept its power.
+    /// The temperature is then decoded with the configured data format.
+    pub async fn new_max3172x_synced<A: Into<Address>>(
+        i2c: I2C,
+        address: A,
+    ) -> Result<Self, Error<E>> {
+        let mut sensor = Self::new_max3172x(i2c, address);
+        sensor.config = sensor.read_config().await?;
+        Ok(sensor)
+    }
+
+    /// Enable the extended data format.
+    ///
+    /// The temperature and thresholds are then encoded with an offset of
+    /// 64ºC, so that temperatures above 127ºC can be represented.
+    /// The thresholds are not converted by the device and must be set
+    /// again afterwards.
+    pub async fn enable_extended_format(&mut self) -> Result<(), Error<E>> {
+        let config = self
+            .current_config()
+            .await?
+            .with_high(BitFlags::EXTENDED_FORMAT);
+        self.write_config(config).await
+    }
+
+    /// Disable the extended data format (default).
+    ///
+    /// The thresholds are not converted by the device and must be set
+    /// again afterwards.
+    pub async fn disable_extended_format(&mut self) -> Result<(), Error<E>> {
+        let config = self
+            .current_config()
+            .await?
+            .with_low(BitFlags::EXTENDED_FORMAT);
+        self.write_config(config).await
+    }
+}
+
+#[cfg(feature = "ds7505")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, E> Lm75<I2C, ic::Ds7505>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create new instance of the DS7505 device.
+    pub fn new_ds7505<A: Into<Address>>(i2c: I2C, address: A) -> Self {
+        Self::create(i2c, address.into(), ic::Ds7505)
+    }
+
+    /// Create new instance of the DS7505 device reading the current
+    /// configuration from the device instead of assuming power-on defaults.
+    ///
+    /// This is useful when the device may have been configured before,
+    /// for example if the MCU was reset but the sensor kept its power.
+    /// The temperature is then decoded with the configured resolution.
+    pub async fn new_ds7505_synced<A: Into<Address>>(
+        i2c: I2C,
+        address: A,
+    ) -> Result<Self, Error<E>> {
+        let mut sensor = Self::new_ds7505(i2c, address);
+        sensor.config = sensor.read_config().await?;
+        Ok(sensor)
+    }
+
+    /// Recall the configuration and thermostat limits from the
+    /// nonvolatile memory.
+    ///
+    /// This issues a software power-on reset, after which the device
+    /// behaves as after being powered up. The configuration is then read
+    /// to update the cached configuration.
+    ///
+    /// If the nonvolatile memory is being written, `Error::Busy` is
+    /// returned without issuing the command.
+    pub async fn recall_nv(&mut self) -> Result<(), Error<E>> {
+        self.send_nv_command(Command::SOFTWARE_POR).await?;
+        self.config = self.read_config().await?;
+        Ok(())
+    }
+
+    /// Copy the configuration and thermostat limits to the nonvolatile
+    /// memory.
+    ///
+    /// The stored values are loaded on power-up. If the nonvolatile memory
+    /// is being written, `Error::Busy` is returned without issuing the
+    /// command. Writing takes up to 10ms, during which `is_nv_busy()`
+    /// returns `true`.
+    pub async fn copy_to_nv(&mut self) -> Result<(), Error<E>> {
+        self.send_nv_command(Command::COPY_DATA).await
+    }
+
+    /// Read whether the nonvolatile memory is being written.
+    pub async fn is_nv_busy(&mut self) -> Result<bool, Error<E>> {
+        let config = self.read_config().await?;
+        Ok((config.bits & BitFlags::NV_BUSY) != 0)
+    }
+
+    /// send a command if the nonvolatile memory is not being written
+    async fn send_nv_command(&mut self, command: u8) -> Result<(), Error<E>> {
+        if self.is_nv_busy().await? {
+            return Err(Error::Busy);
+        }
+        let register = Register::Other(command);
+        self.write_register_unverified(register, &[command]).await
+    }
+}
+
+#[cfg(feature = "at30ts75a")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, E> Lm75<I2C, ic::At30ts75a>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create new instance of the AT30TS75A device.
+    pub fn new_at30ts75a<A: Into<Address>>(i2c: I2C, address: A) -> Self {
+        Self::create(i2c, address.into(), ic::At30ts75a)
+    }
+
+    /// Create new instance of the AT30TS75A device reading the current
+    /// configuration from the device instead of assuming power-on defaults.
+    ///
+    /// This is useful when the device may have been configured before,
+    /// for example if the MCU was reset but the sensor kept its power.
+    /// The temperature is then decoded with the configured resolution.
+    pub async fn new_at30ts75a_synced<A: Into<Address>>(
+        i2c: I2C,
+        address: A,
+    ) -> Result<Self, Error<E>> {
+        let mut sensor = Self::new_at30ts75a(i2c, address);
+        sensor.config = sensor.read_config().await?;
+        Ok(sensor)
+    }
+
+    /// Copy the configuration and thermostat limits to the nonvolatile
+    /// registers.
+    ///
+    /// The nonvolatile registers are loaded on power-up. If they are being
+    /// written, `Error::Busy` is returned without issuing the command.
+    /// While the write cycle is in progress, `is_nv_busy()` returns `true`.
+    pub async fn copy_to_nv(&mut self) -> Result<(), Error<E>> {
+        self.send_nv_command(Command::COPY_DATA).await
+    }
+
+    /// Copy the configuration and thermostat limits from the nonvolatile
+    /// registers.
+    ///
+    /// The configuration is then read to update the cached configuration.
+    /// If the nonvolatile registers are being written, `Error::Busy` is
+    /// returned without issuing the command.
+    pub async fn copy_from_nv(&mut self) -> Result<(), Error<E>> {
+        self.send_nv_command(Command::RECALL_DATA).await?;
+        self.config = self.read_config().await?;
+        Ok(())
+    }
+
+    /// Read whether the nonvolatile registers are being written.
+    pub async fn is_nv_busy(&mut self) -> Result<bool, Error<E>> {
+        let mut data = [0; 2];
+        self.read_register(Register::Configuration, &mut data)
+            .await?;
+        Ok((data[1] & BitFlags::NV_REGISTER_BUSY) != 0)
+    }
+
+    /// Lock the nonvolatile registers against writes.
+    ///
+    /// This can be undone with `unlock_nv_registers()` unless the registers
+    /// have been locked down. If the nonvolatile registers are being
+    /// written, `Error::Busy` is returned without modifying the device.
+    pub async fn lock_nv_registers(&mut self) -> Result<(), Error<E>> {
+        self.modify_nv_lock(|lock| lock | BitFlags::REGISTER_LOCK)
+            .await
+    }
+
+    /// Unlock the nonvolatile registers.
+    ///
+    /// This has no effect if the registers have been locked down.
+    /// If the nonvolatile registers are being written, `Error::Busy` is
+    /// returned without modifying the device.
+    pub async fn unlock_nv_registers(&mut self) -> Result<(), Error<E>> {
+        self.modify_nv_lock(|lock| lock & !BitFlags::REGISTER_LOCK)
+            .await
+    }
+
+    /// Permanently lock the nonvolatile registers against writes.
+    ///
+    /// **Warning**: This cannot be undone. The thresholds and configuration
+    /// stored in the nonvolatile registers will be frozen for the lifetime
+    /// of the device. If the nonvolatile registers are being written,
+    /// `Error::Busy` is returned without modifying the device.
+    pub async fn lock_down_nv_registers(&mut self) -> Result<(), Error<E>> {
+        self.modify_nv_lock(|lock| lock | BitFlags::REGISTER_LOCKDOWN)
+            .await
+    }
+
+    /// Read whether the nonvolatile registers are locked or locked down.
+    pub async fn is_nv_locked(&mut self) -> Result<bool, Error<E>> {
+        let mut data = [0; 2];
+        self.read_register(NV_CONFIGURATION, &mut data).await?;
+        Ok((data[1] & (BitFlags::REGISTER_LOCK | BitFlags::REGISTER_LOCKDOWN)) != 0)
+    }
+
+    /// modify the lock bits of the nonvolatile configuration register
+    async fn modify_nv_lock<F>(&mut self, f: F) -> Result<(), Error<E>>
+    where
+        F: FnOnce(u8) -> u8,
+    {
+        if self.is_nv_busy().await? {
+            return Err(Error::Busy);
+        }
+        let mut data = [0; 2];
+        self.read_register(NV_CONFIGURATION, &mut data).await?;
+        data[1] = f(data[1]);
+        self.write_register(NV_CONFIGURATION, &data).await
+    }
+
+    /// send a command if the nonvolatile registers are not being written
+    async fn send_nv_command(&mut self, command: u8) -> Result<(), Error<E>> {
+        if self.is_nv_busy().await? {
+            return Err(Error::Busy);
+        }
+        let register = Register::Other(command);
+        self.write_register_unverified(register, &[command]).await
+    }
+}
+
+#[cfg(feature = "max750x")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, E> Lm75<I2C, ic::Max750x>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create new instance of the MAX7500/1/2/3/4 device.
+    pub fn new_max750x<A: Into<Address>>(i2c: I2C, address: A) -> Self {
+        Self::create(i2c, address.into(), ic::Max750x)
+    }
+
+    /// Create new instance of the MAX7500/1/2/3/4 device reading the current
+    /// configuration from the device instead of assuming power-on defaults.
+    ///
+    /// This is useful when the device may have been configured before,
+    /// for example if the MCU was reset but the sensor kept its power.
+    pub async fn new_max750x_synced<A: Into<Address>>(
+        i2c: I2C,
+        address: A,
+    ) -> Result<Self, Error<E>> {
+        let mut sensor = Self::new_max750x(i2c, address);
+        sensor.config = sensor.read_config().await?;
+        Ok(sensor)
+    }
+
+    /// Enable the I²C bus timeout (default).
+    ///
+    /// The device resets its bus interface if the clock or data line
+    /// is held low for too long.
+    pub async fn enable_bus_timeout(&mut self) -> Result<(), Error<E>> {
+        let config = self
+            .current_config()
+            .await?
+            .with_low(BitFlags::BUS_TIMEOUT_DISABLE);
+        self.write_config(config).await
+    }
+
+    /// Disable the I²C bus timeout.
+    ///
+    /// This is necessary for buses which may be held low for long periods,
+    /// e.g. by a slow controller, and for SMBus-incompatible operation.
+    pub async fn disable_bus_timeout(&mut self) -> Result<(), Error<E>> {
+        let config = self
+            .current_config()
+            .await?
+            .with_high(BitFlags::BUS_TIMEOUT_DISABLE);
+        self.write_config(config).await
+    }
+}
+
+#[cfg(feature = "max6625")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, E> Lm75<I2C, ic::Max6625>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create new instance of the MAX6625 device.
+    pub fn new_max6625<A: Into<Address>>(i2c: I2C, address: A) -> Self {
+        Self::create(i2c, address.into(), ic::Max6625)
+    }
+
+    /// Create new instance of the MAX6625 device reading the current
+    /// configuration from the device instead of assuming power-on defaults.
+    ///
+    /// This is useful when the device may have been configured before,
+    /// for example if the MCU was reset but the sensor kept its power.
+    pub async fn new_max6625_synced<A: Into<Address>>(
+        i2c: I2C,
+        address: A,
+    ) -> Result<Self, Error<E>> {
+        let mut sensor = Self::new_max6625(i2c, address);
+        sensor.config = sensor.read_config().await?;
+        Ok(sensor)
+    }
+}
+
+#[cfg(feature = "max6626")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, E> Lm75<I2C, ic::Max6626>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create new instance of the MAX6626 device.
+    pub fn new_max6626<A: Into<Address>>(i2c: I2C, address: A) -> Self {
+        Self::create(i2c, address.into(), ic::Max6626)
+    }
+
+    /// Create new instance of the MAX6626 device reading the current
+    /// configuration from the device instead of assuming power-on defaults.
+    ///
+    /// This is useful when the device may have been configured before,
+    /// for example if the MCU was reset but the sensor kept its power.
+    pub async fn new_max6626_synced<A: Into<Address>>(
+        i2c: I2C,
+        address: A,
+    ) -> Result<Self, Error<E>> {
+        let mut sensor = Self::new_max6626(i2c, address);
+        sensor.config = sensor.read_config().await?;
+        Ok(sensor)
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, IC, E> Lm75<I2C, IC>
+where
+    I2C: I2c<Error = E>,
+    IC: ResolutionSupport + HasResolutionConfig,
+{
+    /// Set the temperature conversion resolution.
+    ///
+    /// Temperature readings are decoded according to the new resolution.
+    /// A higher resolution results in a longer conversion time.
+    pub async fn set_resolution(&mut self, resolution: Resolution) -> Result<(), Error<E>> {
+        let config = self.current_config().await?.with_resolution(resolution);
+        self.write_config(config).await
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, IC, E> Lm75<I2C, IC>
+where
+    I2C: I2c<Error = E>,
+    IC: ResolutionSupport + HasSampleRate,
+{
+    /// Set the sensor sample rate period in milliseconds (100ms increments).
+    ///
+    /// For values above 3100, `Error::OutOfRange` will be returned and
+    /// for those not a multiple of 100, `Error::InvalidInputData`.
+    pub async fn set_sample_rate(&mut self, period: u16) -> Result<(), Error<E>> {
+        self.write_sample_rate(period).await
+    }
+
+    /// Apply a power profile, setting the sample rate and shutdown state together.
+    ///
+    /// The sample rate is written before enabling the device so that it never
+    /// samples at a different rate. An invalid period returns an error like
+    /// `set_sample_rate()` without modifying the device.
+    pub async fn set_power_profile(&mut self, profile: PowerProfile) -> Result<(), Error<E>> {
+        match profile {
+            PowerProfile::Normal => {
+                self.write_sample_rate(100).await?;
+                self.enable().await
+            }
+            PowerProfile::LowPower { period_ms } => {
+                self.write_sample_rate(period_ms).await?;
+                self.enable().await
+            }
+            PowerProfile::Shutdown => self.disable().await,
+        }
+    }
+
+    /// Read the sample rate period from the sensor (ms).
+    pub async fn read_sample_rate(&mut self) -> Result<u16, Error<E>> {
+        let mut data = [0; 1];
+        self.read_register(Register::TIdle, &mut data).await?;
+        Ok(conversion::convert_sample_rate_from_register(data[0]))
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(
+        Lm75(sync),
+        AutoShutdown(sync),
+        Sampler(sync),
+        I2c(sync, async = "AsyncI2c"),
+        DelayNs(sync, async = "AsyncDelayNs")
+    )
+)]
+impl<I2C, IC, E> Lm75<I2C, IC>
+where
+    I2C: I2c<Error = E>,
+    IC: ResolutionSupport + RegisterSupport,
+{
+    /// Wait for the maximum temperature conversion time of the device.
+    ///
+    /// The conversion time depends on the configured resolution where
+    /// applicable. This can be used after enabling the device or triggering
+    /// a one-shot conversion to make sure the next reading is valid.
+    pub async fn wait_for_conversion<D: DelayNs>(&self, delay: &mut D) {
+        delay
+            .delay_ms(self.ic.get_conversion_time_ms(self.config))
+            .await;
+    }
+
+    /// Enable the sensor and wait for the first temperature conversion.
+    ///
+    /// This waits for the maximum conversion time at the configured
+    /// resolution so that the next reading is valid.
+    pub async fn enable_and_wait<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<E>> {
+        self.enable().await?;
+        self.wait_for_conversion(delay).await;
+        Ok(())
+    }
+
+    /// Wait until the temperature exceeds the OS temperature or the
+    /// timeout expires.
+    ///
+    /// This is useful if the OS output is not connected. The OS temperature
+    /// is read from the device and the temperature is read after every
+    /// conversion time. Returns immediately if the temperature already
+    /// exceeds the OS temperature. The hysteresis and the fault queue are
+    /// not taken into account.
+    /// If an OS pin is available, use `AlertMonitor::wait_for_alert()`.
+    pub async fn poll_for_alert<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        timeout_ms: u32,
+    ) -> Result<WaitOutcome, Error<E>> {
+        let mut data = [0; 2];
+        self.read_register(Register::TOs, &mut data).await?;
+        let os = conversion::convert_temp_raw_from_register(
+            data[0],
+            data[1],
+            self.ic.get_limit_mask(self.config),
+        );
+        let mut elapsed_ms = 0;
+        loop {
+            if self.read_temperature_raw().await? > os {
+                return Ok(WaitOutcome::Alert);
+            }
+            if elapsed_ms >= timeout_ms {
+                return Ok(WaitOutcome::Timeout);
+            }
+            let wait_ms = self
+                .ic
+                .get_conversion_time_ms(self.config)
+                .min(timeout_ms - elapsed_ms)
+                .max(1);
+            delay.delay_ms(wait_ms).await;
+            elapsed_ms += wait_ms;
+        }
+    }
+
+    /// Shut the device down between temperature readings.
+    ///
+    /// Every `read_temperature()` of the returned driver performs a reading
+    /// like `read_temperature_one_shot()` using the given delay. The device
+    /// stays in its current state until the first reading.
+    pub fn with_auto_shutdown<D: DelayNs>(self, delay: D) -> AutoShutdown<I2C, IC, D> {
+        AutoShutdown {
+            sensor: self,
+            delay,
+        }
+    }
+
+    /// Sample the temperature every `period_ms` milliseconds keeping the
+    /// device in shutdown in between.
+    ///
+    /// See [`Sampler`].
+    pub fn with_duty_cycle<D: DelayNs>(self, delay: D, period_ms: u32) -> Sampler<I2C, IC, D> {
+        Sampler {
+            sensor: self,
+            delay,
+            period_ms,
+            awake_ms: None,
+            started: false,
+        }
+    }
+
+    /// Save the device state and shut the device down.
+    ///
+    /// The returned state can be passed to `resume()`, also if the device
+    /// was powered off in the meantime.
+    pub async fn suspend(&mut self) -> Result<DeviceState, Error<E>> {
+        let state = self.save_state().await?;
+        self.write_config(state.config.with_shutdown(true)).await?;
+        Ok(state)
+    }
+
+    /// Restore a state obtained with `suspend()` and wait for the first
+    /// temperature conversion.
+    ///
+    /// The device is enabled again unless it was already in shutdown when
+    /// suspended, in which case there is no conversion to wait for.
+    pub async fn resume<D: DelayNs>(
+        &mut self,
+        state: &DeviceState,
+        delay: &mut D,
+    ) -> Result<(), Error<E>> {
+        self.restore_state(state).await?;
+        if !state.config.is_shutdown() {
+            self.wait_for_conversion(delay).await;
+        }
+        Ok(())
+    }
+
+    /// Set the power mode.
+    ///
+    /// `PowerMode::OneShot` puts the device into shutdown and starts a
+    /// single conversion. Devices without one-shot conversions return
+    /// `Error::InvalidInputData` for it.
+    pub async fn set_power_mode(&mut self, mode: PowerMode) -> Result<(), Error<E>> {
+        match mode {
+            PowerMode::Continuous => self.enable().await,
+            PowerMode::Shutdown => self.disable().await,
+            PowerMode::OneShot if self.ic.has_one_shot() => self.start_one_shot().await,
+            PowerMode::OneShot => Err(Error::InvalidInputData),
+        }
+    }
+
+    /// Perform a single temperature conversion and read the result (celsius).
+    ///
+    /// On devices supporting one-shot conversions, this triggers one,
+    /// waits for the maximum conversion time at the configured resolution
+    /// and reads the temperature. Other devices are enabled for the
+    /// conversion time instead.
+    /// In both cases, the device is in shutdown afterwards.
+    pub async fn read_temperature_one_shot<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<f32, Error<E>> {
+        let wait_ms = self.ic.get_conversion_time_ms(self.config);
+        self.read_temperature_after(delay, wait_ms).await
+    }
+
+    /// wake the device up, wait and read the temperature leaving the device in shutdown
+    async fn read_temperature_after<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        wait_ms: u32,
+    ) -> Result<f32, Error<E>> {
+        if self.ic.has_one_shot() {
+            self.start_one_shot().await?;
+            delay.delay_ms(wait_ms).await;
+            self.read_temperature().await
+        } else {
+            self.enable().await?;
+            delay.delay_ms(wait_ms).await;
+            let temperature = self.read_temperature().await;
+            self.disable().await?;
+            temperature
+        }
+    }
+
+    /// put the device into shutdown and set the one-shot bit
+    async fn start_one_shot(&mut self) -> Result<(), Error<E>> {
+        let current = self.current_config().await?;
+        let config = current.with_shutdown(true);
+        if !current.is_shutdown() {
+            // The one-shot bit is only effective while in shutdown.
+            self.write_config(config).await?;
+        }
+        let payload = [
+            Register::Configuration.addr(),
+            config.with_high(BitFlags::ONE_SHOT).bits,
+        ];
+        self.write_register_unverified(Register::Configuration, &payload)
+            .await?;
+        self.config = config;
+        self.one_shot_started = true;
+        Ok(())
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(
+        Lm75(sync),
+        AutoShutdown(sync),
+        I2c(sync, async = "AsyncI2c"),
+        DelayNs(sync, async = "AsyncDelayNs")
+    )
+)]
+impl<I2C, IC, D, E> AutoShutdown<I2C, IC, D>
+where
+    I2C: I2c<Error = E>,
+    IC: ResolutionSupport + RegisterSupport,
+    D: DelayNs,
+{
+    /// Read the temperature (celsius), shutting the device down afterwards.
+    pub async fn read_temperature(&mut self) -> Result<f32, Error<E>> {
+        self.sensor.read_temperature_one_shot(&mut self.delay).await
+    }
+
+    /// Access the device driver, for example to configure the device.
+    pub fn sensor_mut(&mut self) -> &mut Lm75<I2C, IC> {
+        &mut self.sensor
+    }
+
+    /// Destroy the driver instance, return the device driver and the delay.
+    pub fn destroy(self) -> (Lm75<I2C, IC>, D) {
+        (self.sensor, self.delay)
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(
+        Lm75(sync),
+        Sampler(sync),
+        I2c(sync, async = "AsyncI2c"),
+        DelayNs(sync, async = "AsyncDelayNs")
+    )
+)]
+impl<I2C, IC, D, E> Sampler<I2C, IC, D>
+where
+    I2C: I2c<Error = E>,
+    IC: ResolutionSupport + RegisterSupport,
+    D: DelayNs,
+{
+    /// Take the next temperature sample (celsius).
+    ///
+    /// The first sample is taken immediately. Afterwards, this waits until
+    /// the sampling period has elapsed since the previous sample started.
+    /// The time the device is awake is the conversion time at the configured
+    /// resolution. On the PCT2075 a new conversion is only guaranteed after
+    /// the configured sample rate period (`TIdle`) as well, so this is added
+    /// and the sample rate is read from the device for the first sample.
+    /// If the period is shorter than the awake time, samples are taken
+    /// back-to-back.
+    pub async fn sample(&mut self) -> Result<f32, Error<E>> {
+        let awake_ms = match self.awake_ms {
+            Some(awake_ms) => awake_ms,
+            None => {
+                let awake_ms = self.sensor.read_awake_time_ms().await?;
+                self.awake_ms = Some(awake_ms);
+                awake_ms
+            }
+        };
+        if self.started {
+            self.delay
+                .delay_ms(self.period_ms.saturating_sub(awake_ms))
+                .await;
+        }
+        self.started = true;
+        self.sensor
+            .read_temperature_after(&mut self.delay, awake_ms)
+            .await
+    }
+
+    /// Access the device driver, for example to configure the device.
+    ///
+    /// The time the device is awake for each sample is determined again
+    /// for the next sample.
+    pub fn sensor_mut(&mut self) -> &mut Lm75<I2C, IC> {
+        self.awake_ms = None;
+        &mut self.sensor
+    }
+
+    /// Destroy the sampler instance, return the device driver and the delay.
+    pub fn destroy(self) -> (Lm75<I2C, IC>, D) {
+        (self.sensor, self.delay)
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, IC, E> Lm75<I2C, IC>
+where
+    I2C: I2c<Error = E>,
+    IC: ResolutionSupport + RegisterSupport + HasOneShot,
+{
+    /// Start a single temperature conversion.
+    ///
+    /// The device is put into shutdown and performs a single conversion
+    /// after which it returns to shutdown. The result can be read with
+    /// `read_temperature()` after the conversion time has elapsed.
+    pub async fn trigger_one_shot(&mut self) -> Result<(), Error<E>> {
+        self.start_one_shot().await
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, IC, E> Lm75<I2C, IC>
+where
+    I2C: I2c<Error = E>,
+    IC: HasProductId,
+{
+    /// Read the product ID register of the NXP LM75A.
+    ///
+    /// Other LM75 devices do not provide this register.
+    pub async fn read_product_id(&mut self) -> Result<u8, Error<E>> {
+        let mut data = [0];
+        self.read_register(PRODUCT_ID, &mut data).await?;
+        Ok(data[0])
+    }
+
+    /// Verify that the device reports the expected product ID.
+    ///
+    /// This catches a wrong address or an unexpected part fitted on the
+    /// board during initialization. If the product ID does not match,
+    /// `Error::WrongDevice` is returned.
+    pub async fn verify_identity(&mut self, expected_id: u8) -> Result<(), Error<E>> {
+        if self.read_product_id().await? == expected_id {
+            Ok(())
+        } else {
+            Err(Error::WrongDevice)
+        }
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), InterruptMode(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, IC, E> InterruptMode<I2C, IC>
+where
+    I2C: I2c<Error = E>,
+    IC: ResolutionSupport,
+{
+    /// Acknowledge the OS (alert) output.
+    ///
+    /// In interrupt mode, the OS output stays active until any register is
+    /// read. This reads the temperature to clear it and returns whether the
+    /// temperature rose above the OS temperature or fell below the
+    /// hysteresis temperature, by comparing the temperature to the
+    /// hysteresis temperature read from the device.
+    pub async fn acknowledge_alert(&mut self) -> Result<AlertEvent, Error<E>> {
+        let sensor = &mut self.sensor;
+        let mut data = [0; 2];
+        sensor
+            .read_register(Register::Temperature, &mut data)
+            .await?;
+        let temperature = sensor.check_temperature_reading(data, sensor.config)?;
+        sensor.read_register(Register::THyst, &mut data).await?;
+        let hysteresis = conversion::convert_temp_raw_from_register(
+            data[0],
+            data[1],
+            sensor.ic.get_limit_mask(sensor.config),
+        );
+        if temperature < hysteresis {
+            Ok(AlertEvent::BelowHysteresis)
+        } else {
+            Ok(AlertEvent::OverTemperature)
+        }
+    }
+
+    /// Read the temperature from the sensor (celsius).
+    ///
+    /// This also clears the OS output. See `Lm75::read_temperature()`.
+    pub async fn read_temperature(&mut self) -> Result<f32, Error<E>> {
+        self.sensor.read_temperature().await
+    }
+
+    /// Switch the OS output back to comparator mode.
+    pub async fn into_comparator_mode(mut self) -> Result<Lm75<I2C, IC>, Error<E>> {
+        self.sensor.set_os_mode(OsMode::Comparator).await?;
+        Ok(self.sensor)
+    }
+
+    /// Destroy the driver instance, return the device driver.
+    ///
+    /// The device stays in interrupt mode.
+    pub fn destroy(self) -> Lm75<I2C, IC> {
+        self.sensor
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), AlertMonitor(sync), DelayNs(sync, async = "AsyncDelayNs"))
+)]
+impl<I2C, IC, P> AlertMonitor<I2C, IC, P>
+where
+    P: InputPin,
+{
+    /// Filter glitches on the OS (alert) pin.
+    ///
+    /// The state reported by `is_alert_active()` only changes once the
+    /// given number of consecutive pin samples agree on the new state.
+    /// Every call to `is_alert_active()` takes one sample, so glitches
+    /// shorter than the sampling interval times `samples` are ignored.
+    /// The default is one sample, i.e. no filtering. Zero is treated as one.
+    pub fn with_debounce(mut self, samples: u8) -> Self {
+        self.debounce_samples = samples.max(1);
+        self
+    }
+
+    /// Whether the OS (alert) output is active.
+    ///
+    /// The pin level is interpreted according to the configured OS polarity
+    /// and filtered as configured with `with_debounce()`.
+    pub fn is_alert_active(&mut self) -> Result<bool, P::Error> {
+        let active = match self.sensor.config.os_polarity() {
+            OsPolarity::ActiveLow => self.os_pin.is_low()?,
+            OsPolarity::ActiveHigh => self.os_pin.is_high()?,
+        };
+        if active == self.pin_active {
+            self.pending_samples = 0;
+        } else {
+            self.pending_samples += 1;
+            if self.pending_samples >= self.debounce_samples {
+                self.pin_active = active;
+                self.pending_samples = 0;
+            }
+        }
+        self.latched |= self.pin_active;
+        Ok(self.pin_active)
+    }
+
+    /// Whether the OS (alert) output was active in any sample taken since
+    /// the latch was last cleared.
+    ///
+    /// The pin is sampled by `is_alert_active()`, `poll()` and
+    /// `wait_for_alert()`, so that an alert seen by any of them is not
+    /// missed by a slower loop checking this. See `clear_latch()`.
+    pub fn alert_latched(&self) -> bool {
+        self.latched
+    }
+
+    /// Clear the alert latch. See `alert_latched()`.
+    pub fn clear_latch(&mut self) {
+        self.latched = false;
+    }
+
+    /// Check the OS (alert) output and report a change of the alert state.
+    ///
+    /// Call this regularly, e.g. from the main loop, to handle alarms in a
+    /// single place. Returns the event if the state changed since the
+    /// previous call, otherwise `None`.
+    ///
+    /// In comparator mode, the output becoming active is reported as
+    /// `AlertEvent::OverTemperature` and becoming inactive as
+    /// `AlertEvent::BelowHysteresis`.
+    ///
+    /// In interrupt mode, each time the output becomes active is reported,
+    /// alternating between `AlertEvent::OverTemperature` and
+    /// `AlertEvent::BelowHysteresis` like the device does, starting with an
+    /// over-temperature. The output must then be acknowledged by reading
+    /// any register, e.g. with `read_temperature()` through `sensor_mut()`,
+    /// for further events to be signaled.
+    pub fn poll(&mut self) -> Result<Option<AlertEvent>, P::Error> {
+        let active = self.is_alert_active()?;
+        if active == self.alert_active {
+            return Ok(None);
+        }
+        self.alert_active = active;
+        let over_temperature = match self.sensor.config.os_mode() {
+            OsMode::Comparator => active,
+            OsMode::Interrupt if active => !self.over_temperature,
+            OsMode::Interrupt => return Ok(None),
+        };
+        self.over_temperature = over_temperature;
+        if over_temperature {
+            Ok(Some(AlertEvent::OverTemperature))
+        } else {
+            Ok(Some(AlertEvent::BelowHysteresis))
+        }
+    }
+
+    /// Wait until the OS (alert) output is active or the timeout expires.
+    ///
+    /// The pin is checked every millisecond. Returns immediately if the
+    /// OS output is already active.
+    pub async fn wait_for_alert<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        timeout_ms: u32,
+    ) -> Result<WaitOutcome, P::Error> {
+        let mut elapsed_ms = 0;
+        loop {
+            if self.is_alert_active()? {
+                return Ok(WaitOutcome::Alert);
+            }
+            if elapsed_ms >= timeout_ms {
+                return Ok(WaitOutcome::Timeout);
+            }
+            delay.delay_ms(1).await;
+            elapsed_ms += 1;
+        }
+    }
+
+    /// Access the device driver, for example to configure the device.
+    pub fn sensor_mut(&mut self) -> &mut Lm75<I2C, IC> {
+        &mut self.sensor
+    }
+
+    /// Destroy the driver instance, return the device driver and the pin.
+    pub fn destroy(self) -> (Lm75<I2C, IC>, P) {
+        (self.sensor, self.os_pin)
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async"),
+    idents(Lm75(sync), Lm75Group(sync), I2c(sync, async = "AsyncI2c"))
+)]
+impl<I2C, IC, E> Lm75Group<I2C, IC>
+where
+    I2C: I2c<Error = E>,
+    IC: ResolutionSupport,
+{
+    /// Maximum number of sensors in a group.
+    pub const CAPACITY: usize = GROUP_CAPACITY;
+
+    /// Create an empty group.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a sensor to the group and return its index.
+    ///
+    /// If the group is full or already contains a sensor with the same
+    /// address, the sensor is given back.
+    pub fn add(&mut self, sensor: Lm75<I2C, IC>) -> Result<usize, Lm75<I2C, IC>> {
+        if self.sensors().any(|s| s.address == sensor.address) {
+            return Err(sensor);
+        }
+        match self.sensors.iter().position(Option::is_none) {
+            Some(index) => {
+                self.sensors[index] = Some(sensor);
+                Ok(index)
+            }
+            None => Err(sensor),
+        }
+    }
+
+    /// Number of sensors in the group.
+    pub fn len(&self) -> usize {
+        self.sensors().count()
+    }
+
+    /// Whether the group contains no sensors.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Access the device driver at the given index, for example to
+    /// configure the device.
+    pub fn sensor_mut(&mut self, index: usize) -> Option<&mut Lm75<I2C, IC>> {
+        self.sensors.get_mut(index).and_then(Option::as_mut)
+    }
+
+    /// Read the temperature (celsius) of the sensor at the given index.
+    ///
+    /// Returns `Error::InvalidInputData` if there is no sensor at the index.
+    pub async fn read_temperature(&mut self, index: usize) -> Result<f32, Error<E>> {
+        match self.sensor_mut(index) {
+            Some(sensor) => sensor.read_temperature().await,
+            None => Err(Error::InvalidInputData),
+        }
+    }
+
+    /// Read the temperature (celsius) of every sensor.
+    ///
+    /// The result of each sensor is returned at its index. Indices without
+    /// a sensor are `None`.
+    pub async fn read_temperatures(&mut self) -> [Option<Result<f32, Error<E>>>; GROUP_CAPACITY] {
+        let mut results: [_; GROUP_CAPACITY] = core::array::from_fn(|_| None);
+        for (result, sensor) in results.iter_mut().zip(self.sensors.iter_mut()) {
+            if let Some(sensor) = sensor {
+                *result = Some(sensor.read_temperature().await);
+            }
+        }
+        results
+    }
+
+    /// Read the temperature of every sensor and aggregate the readings.
+    ///
+    /// Sensors which cannot be read are left out of the minimum, maximum
+    /// and mean and their errors are reported at their indices instead.
+    pub async fn read_all(&mut self) -> GroupReading<E> {
+        let mut reading = GroupReading {
+            min: None,
+            max: None,
+            mean: None,
+            count: 0,
+            errors: core::array::from_fn(|_| None),
+        };
+        let mut sum = 0.0;
+        let results = self.read_temperatures().await;
+        for (index, result) in results.into_iter().enumerate() {
+            match result {
+                Some(Ok(temperature)) => {
+                    reading.min = Some(reading.min.map_or(temperature, |t| t.min(temperature)));
+                    reading.max = Some(reading.max.map_or(temperature, |t| t.max(temperature)));
+                    sum += temperature;
+                    reading.count += 1;
+                }
+                Some(Err(e)) => reading.errors[index] = Some(e),
+                None => (),
+            }
+        }
+        if reading.count > 0 {
+            reading.mean = Some(sum / reading.count as f32);
+        }
+        reading
+    }
+
+    /// Destroy the group, return the device drivers at their indices.
+    pub fn destroy(self) -> [Option<Lm75<I2C, IC>>; GROUP_CAPACITY] {
+        self.sensors
+    }
+
+    fn sensors(&self) -> impl Iterator<Item = &Lm75<I2C, IC>> {
+        self.sensors.iter().flatten()
+    }
+}
+
+#[maybe_async_cfg::maybe(sync(keep_self), async(feature = "async"), idents(Lm75Group(sync)))]
+impl<I2C, IC> Default for Lm75Group<I2C, IC> {
+    fn default() -> Self {
+        Lm75Group {
+            sensors: core::array::from_fn(|_| None),
+        }
+    }
+}
+
+impl<I2C, IC, E> TemperatureSensor for Lm75<I2C, IC>
+where
+    I2C: I2c<Error = E>,
+    IC: ResolutionSupport,
+{
+    type Error = Error<E>;
+
+    fn read_temperature(&mut self) -> Result<f32, Self::Error> {
+        Lm75::read_temperature(self)
+    }
+}
+
+impl<I2C, IC, D, E> TemperatureSensor for AutoShutdown<I2C, IC, D>
+where
+    I2C: I2c<Error = E>,
+    IC: ResolutionSupport + RegisterSupport,
+    D: DelayNs,
+{
+    type Error = Error<E>;
+
+    fn read_temperature(&mut self) -> Result<f32, Self::Error> {
+        AutoShutdown::read_temperature(self)
+    }
+}
+
+impl<I2C, IC, E> Lm75Like for Lm75<I2C, IC>
+where
+    I2C: I2c<Error = E>,
+    IC: ResolutionSupport + RegisterSupport,
+{
+    fn read_temperature_raw(&mut self) -> Result<i16, Self::Error> {
+        Lm75::read_temperature_raw(self)
+    }
+
+    fn read_measurement(&mut self) -> Result<Measurement, Self::Error> {
+        Lm75::read_measurement(self)
+    }
+
+    fn resolution(&self) -> f32 {
+        Lm75::resolution(self)
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Lm75::capabilities(self)
+    }
+
+    fn enable(&mut self) -> Result<(), Self::Error> {
+        Lm75::enable(self)
+    }
+
+    fn disable(&mut self) -> Result<(), Self::Error> {
+        Lm75::disable(self)
+    }
+
+    fn set_fault_queue(&mut self, fq: FaultQueue) -> Result<(), Self::Error> {
+        Lm75::set_fault_queue(self, fq)
+    }
+
+    fn set_os_polarity(&mut self, polarity: OsPolarity) -> Result<(), Self::Error> {
+        Lm75::set_os_polarity(self, polarity)
+    }
+
+    fn set_os_mode(&mut self, mode: OsMode) -> Result<(), Self::Error> {
+        Lm75::set_os_mode(self, mode)
+    }
+
+    fn set_os_temperature(&mut self, temperature: f32) -> Result<(), Self::Error> {
+        Lm75::set_os_temperature(self, temperature)?;
+        Ok(())
+    }
+
+    fn set_hysteresis_temperature(&mut self, temperature: f32) -> Result<(), Self::Error> {
+        Lm75::set_hysteresis_temperature(self, temperature)?;
+        Ok(())
+    }
+
+    fn apply_settings(&mut self, settings: &Settings) -> Result<(), Self::Error> {
+        Lm75::apply_settings(self, settings)
     }
 }
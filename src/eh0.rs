@@ -0,0 +1,187 @@
+//! Support for `embedded-hal` 0.2 I²C implementations
+//!
+//! The driver is based on the `embedded-hal` 1.0 traits. I²C
+//! implementations based on `embedded-hal` 0.2 can be used by wrapping
+//! them in an [`I2cAdapter`].
+//!
+//! ```
+//! use embedded_hal_0_2::blocking::i2c::{Write, WriteRead};
+//! use lm75::{eh0::I2cAdapter, Address, Lm75};
+//!
+//! fn read_temperature<I2C, E>(i2c: I2C) -> f32
+//! where
+//!     I2C: Write<Error = E> + WriteRead<Error = E>,
+//!     E: core::fmt::Debug,
+//! {
+//!     let mut sensor = Lm75::new(I2cAdapter::new(i2c), Address::default());
+//!     sensor.read_temperature().unwrap()
+//! }
+//! ```
+//!
+//! Monitoring applications that never configure the device can use a
+//! [`ReadOnlyI2cAdapter`], which only requires the `WriteRead` trait.
+
+use embedded_hal::i2c::{self, ErrorKind, Operation, SevenBitAddress};
+use embedded_hal_0_2::blocking::i2c as i2c_0_2;
+
+/// Adapter implementing the `embedded-hal` 1.0 I²C trait for an
+/// `embedded-hal` 0.2 I²C implementation
+///
+/// Only the `Write` and `WriteRead` traits are required from the wrapped
+/// implementation. In transactions, each write operation followed by a read
+/// operation is performed with `write_read()` and any other write operation
+/// with `write()`. Read operations not preceded by a write operation are not
+/// supported.
+#[derive(Debug, Default)]
+pub struct I2cAdapter<I2C> {
+    i2c: I2C,
+}
+
+impl<I2C> I2cAdapter<I2C> {
+    /// Wrap an `embedded-hal` 0.2 I²C implementation.
+    pub fn new(i2c: I2C) -> Self {
+        I2cAdapter { i2c }
+    }
+
+    /// Return the wrapped I²C implementation.
+    pub fn into_inner(self) -> I2C {
+        self.i2c
+    }
+}
+
+/// Errors of the [`I2cAdapter`]
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum I2cError<E> {
+    /// Error of the wrapped I²C implementation
+    I2C(E),
+    /// The requested operation is not supported by the adapter
+    Unsupported,
+}
+
+impl<E: core::fmt::Debug> i2c::Error for I2cError<E> {
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Other
+    }
+}
+
+impl<I2C, E> i2c::ErrorType for I2cAdapter<I2C>
+where
+    I2C: i2c_0_2::Write<Error = E> + i2c_0_2::WriteRead<Error = E>,
+    E: core::fmt::Debug,
+{
+    type Error = I2cError<E>;
+}
+
+impl<I2C, E> i2c::I2c for I2cAdapter<I2C>
+where
+    I2C: i2c_0_2::Write<Error = E> + i2c_0_2::WriteRead<Error = E>,
+    E: core::fmt::Debug,
+{
+    fn transaction(
+        &mut self,
+        address: SevenBitAddress,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        let mut operations = operations.iter_mut().peekable();
+        while let Some(operation) = operations.next() {
+            match operation {
+                Operation::Write(bytes) => {
+                    match operations.next_if(|op| matches!(op, Operation::Read(_))) {
+                        Some(Operation::Read(buffer)) => {
+                            self.i2c.write_read(address, bytes, buffer)
+                        }
+                        _ => self.i2c.write(address, bytes),
+                    }
+                    .map_err(I2cError::I2C)?;
+                }
+                Operation::Read(_) => return Err(I2cError::Unsupported),
+            }
+        }
+        Ok(())
+    }
+
+    fn write(&mut self, address: SevenBitAddress, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.i2c.write(address, bytes).map_err(I2cError::I2C)
+    }
+
+    fn write_read(
+        &mut self,
+        address: SevenBitAddress,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.i2c
+            .write_read(address, bytes, buffer)
+            .map_err(I2cError::I2C)
+    }
+}
+
+/// Adapter implementing the `embedded-hal` 1.0 I²C trait for an
+/// `embedded-hal` 0.2 I²C implementation only able to read
+///
+/// Only the `WriteRead` trait is required from the wrapped implementation.
+/// This allows reading the temperature through restricted implementations
+/// like some bus-sharing wrappers. In transactions, each write operation
+/// must be followed by a read operation and is performed with
+/// `write_read()`. Any other operation is not supported, so the device
+/// cannot be configured through this adapter.
+#[derive(Debug, Default)]
+pub struct ReadOnlyI2cAdapter<I2C> {
+    i2c: I2C,
+}
+
+impl<I2C> ReadOnlyI2cAdapter<I2C> {
+    /// Wrap an `embedded-hal` 0.2 I²C implementation.
+    pub fn new(i2c: I2C) -> Self {
+        ReadOnlyI2cAdapter { i2c }
+    }
+
+    /// Return the wrapped I²C implementation.
+    pub fn into_inner(self) -> I2C {
+        self.i2c
+    }
+}
+
+impl<I2C, E> i2c::ErrorType for ReadOnlyI2cAdapter<I2C>
+where
+    I2C: i2c_0_2::WriteRead<Error = E>,
+    E: core::fmt::Debug,
+{
+    type Error = I2cError<E>;
+}
+
+impl<I2C, E> i2c::I2c for ReadOnlyI2cAdapter<I2C>
+where
+    I2C: i2c_0_2::WriteRead<Error = E>,
+    E: core::fmt::Debug,
+{
+    fn transaction(
+        &mut self,
+        address: SevenBitAddress,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        let mut operations = operations.iter_mut();
+        while let Some(operation) = operations.next() {
+            match (operation, operations.next()) {
+                (Operation::Write(bytes), Some(Operation::Read(buffer))) => self
+                    .i2c
+                    .write_read(address, bytes, buffer)
+                    .map_err(I2cError::I2C)?,
+                _ => return Err(I2cError::Unsupported),
+            }
+        }
+        Ok(())
+    }
+
+    fn write_read(
+        &mut self,
+        address: SevenBitAddress,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.i2c
+            .write_read(address, bytes, buffer)
+            .map_err(I2cError::I2C)
+    }
+}
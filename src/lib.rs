@@ -6,12 +6,62 @@
 //! This driver allows you to:
 //! - Enable/disable the device.
 //! - Read the temperature.
+//! - Read the temperature together with the shutdown state.
+//! - Read the temperature in non-blocking steps.
+//! - Read and set temperatures in fahrenheit and kelvin.
+//! - Read and set temperatures in millidegrees celsius without floating-point math.
+//! - Read the temperature as `f64`. See: `read_temperature_f64()`.
+//! - Decode and encode raw Q8.8 register values. See: `RawTemperature`.
+//! - Use temperatures as `Celsius` values with comparisons and arithmetic.
+//! - Set temperatures in any supported format. See: `IntoTemperature`.
+//! - Round temperatures to the device resolution by truncation or to the nearest value.
 //! - Set the fault queue.
 //! - Set the OS temperature.
 //! - Set the hysteresis temperature.
 //! - Set the OS operation mode.
 //! - Set the OS polarity.
 //! - Set the sample rate of temperature measurements (PCT2075 only)
+//! - Set the sample rate and shutdown state together as a power profile (PCT2075 only)
+//! - Set the temperature resolution (MCP980x, TCN75A, TMP75, STDS75, DS7505 and AT30TS75A only)
+//! - Perform one-shot temperature conversions (MCP980x, TCN75A, TMP75, TMP1075 and MAX31725/6 only)
+//! - Read the temperature in a single low-power step shutting the device down afterwards.
+//! - Set the power mode (continuous, shutdown or one-shot).
+//! - Shut the device down automatically between temperature readings.
+//! - Sample the temperature periodically keeping the device in shutdown in between.
+//! - Estimate the average supply current of the device.
+//! - Suspend and resume the device around system sleep.
+//! - Set the conversion rate (TMP1075 only)
+//! - Read the device ID (TMP1075 only) and manufacturer ID (SE95 only)
+//! - Read the product ID and verify the device identity (LM75A only)
+//! - Store and recall the settings in nonvolatile memory (DS7505 and AT30TS75A only)
+//! - Lock the nonvolatile registers (AT30TS75A only)
+//! - Enable/disable the I²C bus timeout (MAX750x only)
+//! - Enable/disable the extended data format (MAX31725/6 only)
+//! - Check the OS (alert) output through the attached pin. See: `AlertMonitor`.
+//! - Acknowledge alerts in interrupt mode. See: `InterruptMode`.
+//! - Handle alert state changes in one place. See: `AlertMonitor::poll()`.
+//! - Filter glitches on the OS pin. See: `with_debounce()`.
+//! - Notice short alerts between slow checks. See: `alert_latched()`.
+//! - Wait for an alert with a timeout, also without an OS pin. See: `poll_for_alert()`.
+//! - Emulate the OS output on a GPIO in software. See: `SoftwareOs`.
+//! - Under/over-temperature window alarm in software. See: `WindowAlarm`.
+//! - Configure the alert in one call. See: `configure_alert()`.
+//! - Manage several sensors as a group. See: `Lm75Group`.
+//! - Read the minimum, maximum and mean temperature of a group. See: `read_all()`.
+//! - Apply all settings at once.
+//! - Wait for the temperature conversion time of the device.
+//! - Validate custom I²C addresses. See: `Address::new()`.
+//! - Enumerate the selectable addresses. See: `Address::lm75_range()`.
+//! - Compute the PCT2075 address from floating address pins. See: `PinLevel`.
+//! - Check whether a device is present. See: `probe()`.
+//! - Scan the bus for LM75-family devices. See: `Lm75::scan()`.
+//! - Get and change the device address at runtime.
+//! - Select the device variant at runtime.
+//! - Describe the capabilities of the device at runtime.
+//! - Use drivers for different devices together through the `Lm75Like` trait.
+//! - Use other compatible devices with a known resolution.
+//! - Convert the driver for another device after probing it.
+//! - Support further devices by implementing the capability traits.
 //!
 //! ## The device
 //!
@@ -36,7 +86,8 @@
 //!
 //! This driver is also compatible with at least [LM75A], [LM75B, LM75C],
 //! [AT30TS75A], [DS1775], [DS75], [DS7505], [G751], [MAX7500/1/2/3/4],
-//! [MAX6625], [MCP9800/1/2/3], [STDS75], [TCN75], [PCT2075].
+//! [MAX6625/6], [MAX31725/6], [MCP9800/1/2/3], [STDS75], [STLM75], [TCN75], [TCN75A],
+//! [TMP75/TMP175], [TMP275], [TMP1075], [SE95], [PCT2075].
 //!
 //! [AT30TS75A]: http://ww1.microchip.com/downloads/en/DeviceDoc/Atmel-8839-DTS-AT30TS75A-Datasheet.pdf
 //! [DS1775]: https://datasheets.maximintegrated.com/en/ds/DS1775-DS1775R.pdf
@@ -45,13 +96,43 @@
 //! [G751]: http://www.gmt.com.tw/product/datasheet/EDS-751.pdf
 //! [LM75A]: https://www.nxp.com/docs/en/data-sheet/LM75A.pdf
 //! [LM75B, LM75C]: http://www.ti.com/lit/ds/symlink/lm75b.pdf
-//! [MAX6625]: https://datasheets.maximintegrated.com/en/ds/MAX6625-MAX6626.pdf
+//! [MAX6625/6]: https://datasheets.maximintegrated.com/en/ds/MAX6625-MAX6626.pdf
+//! [MAX31725/6]: https://datasheets.maximintegrated.com/en/ds/MAX31725-MAX31726.pdf
 //! [MAX7500/1/2/3/4]: https://datasheets.maximintegrated.com/en/ds/MAX7500-MAX7504.pdf
 //! [MCP9800/1/2/3]: http://ww1.microchip.com/downloads/en/DeviceDoc/21909d.pdf
 //! [STDS75]: https://www.st.com/resource/en/datasheet/stds75.pdf
+//! [STLM75]: https://www.st.com/resource/en/datasheet/stlm75.pdf
 //! [TCN75]: http://ww1.microchip.com/downloads/en/DeviceDoc/21490D.pdf
+//! [TCN75A]: http://ww1.microchip.com/downloads/en/DeviceDoc/21935D.pdf
+//! [TMP75/TMP175]: https://www.ti.com/lit/ds/symlink/tmp75.pdf
+//! [TMP275]: https://www.ti.com/lit/ds/symlink/tmp275.pdf
+//! [TMP1075]: https://www.ti.com/lit/ds/symlink/tmp1075.pdf
+//! [SE95]: https://www.nxp.com/docs/en/data-sheet/SE95.pdf
 //! [PCT2075]: https://www.nxp.com/docs/en/data-sheet/PCT2075.pdf
 //!
+//! ## Features
+//!
+//! - `eh0`: Support for I²C implementations based on `embedded-hal` 0.2
+//!   through the adapter in the [`eh0`] module.
+//! - `async`: Asynchronous interface based on `embedded-hal-async` through
+//!   [`Lm75Async`]. This requires Rust 1.75 or later.
+//! - `core-error`: Implementation of `core::error::Error` for [`Error`] so
+//!   that it can be used with error handling libraries like `anyhow`.
+//!   This requires Rust 1.81 or later.
+//! - `defmt`: Implementation of `defmt::Format` for the public types.
+//! - `serde`: Implementation of `serde::Serialize` and `serde::Deserialize`
+//!   for the configuration types like [`Settings`].
+//! - `log`: Trace events through the `log` crate for every register access
+//!   including the device address, register and data.
+//! - `fixed`: Read and set temperatures as fixed-point numbers of the `fixed`
+//!   crate like `I9F7` or `I16F16`.
+//! - `uom`: Read and set temperatures as `uom::si::f32::ThermodynamicTemperature`
+//!   quantities.
+//! - `pct2075`, `lm75a`, `g751`, `mcp980x`, `ds7505`, `at30ts75a`, `max750x`,
+//!   `max6625`, `max6626`, `tcn75a`, `tmp75`, `tmp1075`, `stds75`, `stlm75`,
+//!   `se95`, `max3172x`: Support for the respective devices. These are enabled
+//!   by default. The LM75 is always supported.
+//!
 //! ## Usage examples (see also examples folder)
 //!
 //! To use this driver, import this crate and an `embedded_hal` implementation,
@@ -83,20 +164,53 @@
 //! let dev = I2cdev::new("/dev/i2c-1").unwrap();
 //! let (a2, a1, a0) = (false, false, true);
 //! let address = Address::from((a2,a1,a0));
+//! assert_eq!(Address::A1, address);
 //! let mut sensor = Lm75::new(dev, address);
 //! ```
 //!
 //! ### Provide a full custom address
 //!
 //! ```no_run
+//! # #[cfg(feature = "pct2075")]
+//! # {
 //! use linux_embedded_hal::I2cdev;
 //! use lm75::{Lm75, Address};
 //!
 //! let dev = I2cdev::new("/dev/i2c-1").unwrap();
 //! let all_pins_floating = 0x37; // PCT2075 supports 27 addresses
-//! let address = Address::from(all_pins_floating);
+//! let address = Address::new(all_pins_floating).unwrap();
 //! let mut sensor = Lm75::new_pct2075(dev, address);
+//! # }
 //! ```
+//!
+//! ### Provide a PCT2075 address with floating pins
+//!
+//! ```no_run
+//! # #[cfg(feature = "pct2075")]
+//! # {
+//! use linux_embedded_hal::I2cdev;
+//! use lm75::{Lm75, Address, PinLevel};
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let address = Address::from((PinLevel::Float, PinLevel::Low, PinLevel::High));
+//! let mut sensor = Lm75::new_pct2075(dev, address);
+//! # }
+//! ```
+//!
+//! ### Keep the current device configuration
+//!
+//! If the sensor may have been configured before (e.g. the MCU was reset
+//! but the sensor kept its power), read the configuration from the device.
+//!
+//! ```no_run
+//! use linux_embedded_hal::I2cdev;
+//! use lm75::{Lm75, Address};
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut sensor = Lm75::new_synced(dev, Address::default()).unwrap();
+//! sensor.disable().unwrap(); // other configuration bits are kept
+//! ```
+//!
 //! ### Set the fault queue
 //!
 //! This is the number of consecutive faults necessary to trigger
@@ -159,6 +273,8 @@
 //! ### Set the Sample Rate (PCT2075 only)
 //!
 //! ```no_run
+//! # #[cfg(feature = "pct2075")]
+//! # {
 //! use linux_embedded_hal::I2cdev;
 //! use lm75::{Lm75, Address};
 //!
@@ -166,46 +282,457 @@
 //! let mut sensor = Lm75::new_pct2075(dev, Address::default());
 //! let period = 1500; // in milliseconds, max = 3100, default 100
 //! sensor.set_sample_rate(period).unwrap();
+//! # }
 //! ```
 //!
-//! ### Enable / disable the sensor
+//! ### Set the resolution
 //!
 //! ```no_run
+//! # #[cfg(feature = "mcp980x")]
+//! # {
 //! use linux_embedded_hal::I2cdev;
+//! use lm75::{Lm75, Address, Resolution};
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut sensor = Lm75::new_mcp980x(dev, Address::default());
+//! sensor.set_resolution(Resolution::Bits12).unwrap();
+//! let temp_celsius = sensor.read_temperature().unwrap(); // 0.0625ºC steps
+//! # }
+//! ```
+//!
+//! ### Perform a one-shot conversion
+//!
+//! The device stays in shutdown between conversions to save power.
+//! Devices without one-shot conversions are enabled only for the conversion.
+//! With [`Lm75Async`], the conversion time is awaited on an
+//! `embedded_hal_async::delay::DelayNs` so that the executor can sleep.
+//!
+//! ```no_run
+//! # #[cfg(feature = "mcp980x")]
+//! # {
+//! use linux_embedded_hal::{Delay, I2cdev};
+//! use lm75::{Lm75, Address};
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut sensor = Lm75::new_mcp980x(dev, Address::default());
+//! let temp_celsius = sensor.read_temperature_one_shot(&mut Delay).unwrap();
+//! # }
+//! ```
+//!
+//! ### Shut the device down between readings
+//!
+//! ```no_run
+//! use linux_embedded_hal::{Delay, I2cdev};
+//! use lm75::{Lm75, Address};
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut sensor = Lm75::new(dev, Address::default()).with_auto_shutdown(Delay);
+//! let temp_celsius = sensor.read_temperature().unwrap();
+//! ```
+//!
+//! ### Sample the temperature periodically
+//!
+//! The device stays in shutdown between the samples taken every second.
+//!
+//! ```no_run
+//! # #[cfg(feature = "pct2075")]
+//! # {
+//! use linux_embedded_hal::{Delay, I2cdev};
+//! use lm75::{Lm75, Address};
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut sampler = Lm75::new_pct2075(dev, Address::default()).with_duty_cycle(Delay, 1000);
+//! loop {
+//!     let temp_celsius = sampler.sample().unwrap();
+//! }
+//! # }
+//! ```
+//!
+//! ### Select the device variant at runtime
+//!
+//! ```no_run
+//! # #[cfg(feature = "pct2075")]
+//! # {
+//! use linux_embedded_hal::I2cdev;
+//! use lm75::{Lm75, Address, Variant};
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let populated = Variant::Pct2075; // e.g. determined by probing
+//! let mut sensor = Lm75::new_dynamic(dev, Address::default(), populated);
+//! let temp_celsius = sensor.read_temperature().unwrap();
+//! # }
+//! ```
+//!
+//! ### Support a further device
+//!
+//! ```no_run
+//! use linux_embedded_hal::{Delay, I2cdev};
+//! use lm75::{ic, Address, Config, Lm75};
+//!
+//! struct MySensor; // 10-bit resolution, 30ms conversions
+//!
+//! impl ic::ResolutionSupport for MySensor {
+//!     fn get_resolution_mask(&self, _: Config) -> u16 {
+//!         0xFFC0
+//!     }
+//!
+//!     fn get_conversion_time_ms(&self, _: Config) -> u32 {
+//!         30
+//!     }
+//! }
+//!
+//! impl ic::RegisterSupport for MySensor {
+//!     fn has_one_shot(&self) -> bool {
+//!         true
+//!     }
+//! }
+//!
+//! impl ic::HasOneShot for MySensor {}
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut sensor = Lm75::new_custom(dev, Address::default(), MySensor);
+//! let temp_celsius = sensor.read_temperature_one_shot(&mut Delay).unwrap();
+//! ```
+//!
+//! ### Enable / disable the sensor
+//!
+//! The temperature can only be read again once the first conversion after
+//! enabling the sensor is complete.
+//!
+//! ```no_run
+//! use linux_embedded_hal::{Delay, I2cdev};
 //! use lm75::{Lm75, Address};
 //!
 //! let dev = I2cdev::new("/dev/i2c-1").unwrap();
 //! let mut sensor = Lm75::new(dev, Address::default());
 //! sensor.disable().unwrap(); // shutdown
-//! sensor.enable().unwrap();
+//! sensor.enable_and_wait(&mut Delay).unwrap();
+//! let temp_celsius = sensor.read_temperature().unwrap();
+//! ```
+//!
+//! ### Apply all settings at once
+//!
+//! ```no_run
+//! use linux_embedded_hal::I2cdev;
+//! use lm75::{Lm75, Address, FaultQueue, OsMode, Settings};
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut sensor = Lm75::new(dev, Address::default());
+//! let settings = Settings {
+//!     fault_queue: FaultQueue::_4,
+//!     os_mode: OsMode::Interrupt,
+//!     os_temperature: 50.0,
+//!     hysteresis_temperature: 45.0,
+//!     ..Default::default()
+//! };
+//! sensor.apply_settings(&settings).unwrap();
 //! ```
 
 #![deny(missing_docs, unsafe_code)]
 #![no_std]
 
-use core::marker::PhantomData;
+use embedded_hal::i2c;
 
 /// All possible errors in this crate
 #[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Error<E> {
     /// I²C bus error
-    I2C(E),
-    /// Invalid input data
+    I2C {
+        /// Register access during which the error occurred
+        access: Access,
+        /// Error of the I²C implementation
+        source: E,
+    },
+    /// Invalid input data, e.g. a NaN or infinite temperature
     InvalidInputData,
+    /// Input value outside of the supported range
+    OutOfRange,
+    /// Implausible reading from the sensor
+    SensorFault,
+    /// The contents read back after a write did not match the data written
+    WriteVerificationFailed,
+    /// The device is busy, e.g. writing its nonvolatile memory
+    Busy,
+    /// The device identification does not match the expected device
+    WrongDevice,
+    /// The device is in shutdown so that the temperature would be stale
+    Shutdown,
+}
+
+impl<E: core::fmt::Debug> core::fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::I2C { access, source } => {
+                write!(f, "I²C bus error during {:?}: {:?}", access, source)
+            }
+            Error::InvalidInputData => f.write_str("invalid input data"),
+            Error::OutOfRange => f.write_str("input value out of range"),
+            Error::SensorFault => f.write_str("implausible sensor reading"),
+            Error::WriteVerificationFailed => f.write_str("write verification failed"),
+            Error::Busy => f.write_str("device busy"),
+            Error::WrongDevice => f.write_str("unexpected device identification"),
+            Error::Shutdown => f.write_str("device in shutdown"),
+        }
+    }
+}
+
+/// This requires the `core-error` feature.
+#[cfg(feature = "core-error")]
+impl<E: core::fmt::Debug> core::error::Error for Error<E> {}
+
+/// Register access during which an I²C bus error occurred
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Access {
+    /// Reading a register
+    Read(Register),
+    /// Writing a register
+    Write(Register),
+    /// Reading several registers in a single transaction
+    ReadRegisters,
+}
+
+/// Classify errors through the `embedded-hal` I²C error kinds.
+///
+/// I²C bus errors forward the kind of the underlying error. Any other error
+/// is reported as `ErrorKind::Other`.
+impl<E: i2c::Error> i2c::Error for Error<E> {
+    fn kind(&self) -> i2c::ErrorKind {
+        match self {
+            Error::I2C { source, .. } => source.kind(),
+            _ => i2c::ErrorKind::Other,
+        }
+    }
 }
 
 /// I2C device address
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Address(pub(crate) u8);
 
 /// Default address
 impl Default for Address {
     fn default() -> Self {
-        Address(DEVICE_BASE_ADDRESS)
+        Address::DEFAULT
+    }
+}
+
+impl Address {
+    /// Default address with all address pins low (`0x48`)
+    pub const DEFAULT: Address = Address(DEVICE_BASE_ADDRESS);
+    /// Address pins `A2`, `A1`, `A0` low, low, low (`0x48`)
+    pub const A0: Address = Address::from_pins(false, false, false);
+    /// Address pins `A2`, `A1`, `A0` low, low, high (`0x49`)
+    pub const A1: Address = Address::from_pins(false, false, true);
+    /// Address pins `A2`, `A1`, `A0` low, high, low (`0x4A`)
+    pub const A2: Address = Address::from_pins(false, true, false);
+    /// Address pins `A2`, `A1`, `A0` low, high, high (`0x4B`)
+    pub const A3: Address = Address::from_pins(false, true, true);
+    /// Address pins `A2`, `A1`, `A0` high, low, low (`0x4C`)
+    pub const A4: Address = Address::from_pins(true, false, false);
+    /// Address pins `A2`, `A1`, `A0` high, low, high (`0x4D`)
+    pub const A5: Address = Address::from_pins(true, false, true);
+    /// Address pins `A2`, `A1`, `A0` high, high, low (`0x4E`)
+    pub const A6: Address = Address::from_pins(true, true, false);
+    /// Address pins `A2`, `A1`, `A0` high, high, high (`0x4F`)
+    pub const A7: Address = Address::from_pins(true, true, true);
+
+    /// Create a custom address checking that it is a valid 7-bit address.
+    ///
+    /// Addresses that do not fit in 7 bits and the addresses reserved by
+    /// the I²C specification (`0x00..=0x07` and `0x78..=0x7F`) return an
+    /// error as no device can be reached at them.
+    ///
+    /// `TryFrom<u8>` cannot be offered because of the infallible
+    /// `From<u8>` implementation, which accepts any value without checks.
+    ///
+    /// ```
+    /// use lm75::{Address, AddressError};
+    ///
+    /// assert_eq!(Ok(Address::default()), Address::new(0x48));
+    /// assert_eq!(Err(AddressError::Reserved), Address::new(0x00));
+    /// assert_eq!(Err(AddressError::TooLarge), Address::new(0x90));
+    /// ```
+    pub const fn new(address: u8) -> Result<Self, AddressError> {
+        match address {
+            0x80..=0xFF => Err(AddressError::TooLarge),
+            0x00..=0x07 | 0x78..=0x7F => Err(AddressError::Reserved),
+            _ => Ok(Address(address)),
+        }
+    }
+
+    /// Compute the device address from the address pins `A2`, `A1` and
+    /// `A0` where the pins are not floating.
+    ///
+    /// This is the same as `From<(bool, bool, bool)>` but can be evaluated
+    /// at compile time.
+    ///
+    /// ```
+    /// use lm75::{Address, PinLevel};
+    ///
+    /// const SENSOR: Address = Address::from_pins(false, false, true);
+    /// const PCT2075: Address =
+    ///     Address::from_pin_levels(PinLevel::Float, PinLevel::Low, PinLevel::High);
+    /// const CUSTOM: Address = match Address::new(0x4B) {
+    ///     Ok(address) => address,
+    ///     Err(_) => panic!("invalid address"),
+    /// };
+    /// assert_eq!(Address::from(0x49), SENSOR);
+    /// ```
+    pub const fn from_pins(a2: bool, a1: bool, a0: bool) -> Self {
+        Address(DEVICE_BASE_ADDRESS | ((a2 as u8) << 2) | ((a1 as u8) << 1) | a0 as u8)
+    }
+
+    /// Compute the PCT2075 device address from the levels of the address
+    /// pins `A2`, `A1` and `A0`.
+    ///
+    /// This is the same as `From<(PinLevel, PinLevel, PinLevel)>` but can
+    /// be evaluated at compile time.
+    pub const fn from_pin_levels(a2: PinLevel, a1: PinLevel, a0: PinLevel) -> Self {
+        use PinLevel::{Float as F, High as H, Low as L};
+        let address = match (a2, a1, a0) {
+            (L, L, L) => 0x48,
+            (L, L, H) => 0x49,
+            (L, H, L) => 0x4A,
+            (L, H, H) => 0x4B,
+            (H, L, L) => 0x4C,
+            (H, L, H) => 0x4D,
+            (H, H, L) => 0x4E,
+            (H, H, H) => 0x4F,
+            (L, L, F) => 0x70,
+            (L, H, F) => 0x71,
+            (H, L, F) => 0x72,
+            (H, H, F) => 0x73,
+            (L, F, L) => 0x74,
+            (L, F, H) => 0x75,
+            (H, F, L) => 0x76,
+            (H, F, H) => 0x77,
+            (F, L, L) => 0x28,
+            (F, L, H) => 0x29,
+            (F, H, L) => 0x2A,
+            (F, H, H) => 0x2B,
+            (F, L, F) => 0x2C,
+            (F, H, F) => 0x2D,
+            (F, F, L) => 0x2E,
+            (F, F, H) => 0x2F,
+            (L, F, F) => 0x35,
+            (H, F, F) => 0x36,
+            (F, F, F) => 0x37,
+        };
+        Address(address)
+    }
+
+    /// Addresses selectable on an LM75 and most compatible devices
+    /// (`0x48..=0x4F`), in ascending order.
+    ///
+    /// ```
+    /// use lm75::Address;
+    ///
+    /// assert_eq!(8, Address::lm75_range().count());
+    /// assert_eq!(Some(Address::default()), Address::lm75_range().next());
+    /// ```
+    pub fn lm75_range() -> impl Iterator<Item = Address> {
+        (DEVICE_BASE_ADDRESS..=DEVICE_BASE_ADDRESS | 0b111).map(Address)
+    }
+
+    /// Addresses selectable on a PCT2075 with its tri-state address pins,
+    /// in ascending order.
+    ///
+    /// ```
+    /// use lm75::Address;
+    ///
+    /// assert_eq!(27, Address::pct2075_range().count());
+    /// ```
+    pub fn pct2075_range() -> impl Iterator<Item = Address> {
+        PCT2075_ADDRESSES.iter().copied().map(Address)
+    }
+}
+
+/// Set of I²C device addresses
+///
+/// See `Lm75::scan()`.
+///
+/// ```
+/// use lm75::{Address, AddressSet};
+///
+/// let mut set = AddressSet::default();
+/// set.insert(Address::default());
+/// assert!(set.contains(Address::default()));
+/// assert_eq!(1, set.len());
+/// assert_eq!(Some(Address::default()), set.iter().next());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AddressSet(u128);
+
+impl AddressSet {
+    /// Add an address to the set.
+    ///
+    /// Addresses that do not fit in 7 bits are ignored.
+    pub fn insert(&mut self, address: Address) {
+        if address.0 < 0x80 {
+            self.0 |= 1 << address.0;
+        }
+    }
+
+    /// Whether the set contains the address.
+    pub fn contains(&self, address: Address) -> bool {
+        address.0 < 0x80 && (self.0 & (1 << address.0)) != 0
+    }
+
+    /// Number of addresses in the set.
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Iterate over the addresses in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = Address> {
+        let bits = self.0;
+        (0..0x80)
+            .filter(move |a| (bits & (1 << a)) != 0)
+            .map(Address)
+    }
+}
+
+/// Addresses of the PCT2075 in ascending order
+const PCT2075_ADDRESSES: [u8; 27] = [
+    0x28, 0x29, 0x2A, 0x2B, 0x2C, 0x2D, 0x2E, 0x2F, 0x35, 0x36, 0x37, 0x48, 0x49, 0x4A, 0x4B, 0x4C,
+    0x4D, 0x4E, 0x4F, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77,
+];
+
+/// Invalid I²C device address
+///
+/// See `Address::new()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AddressError {
+    /// The address does not fit in 7 bits
+    TooLarge,
+    /// The address is reserved by the I²C specification
+    Reserved,
+}
+
+impl core::fmt::Display for AddressError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AddressError::TooLarge => f.write_str("address does not fit in 7 bits"),
+            AddressError::Reserved => f.write_str("reserved I²C address"),
+        }
     }
 }
 
-/// Support custom (integer) addresses
+/// This requires the `core-error` feature.
+#[cfg(feature = "core-error")]
+impl core::error::Error for AddressError {}
+
+/// Support custom (integer) addresses without any checks. See `Address::new()`.
 impl From<u8> for Address {
     fn from(a: u8) -> Self {
         Address(a)
@@ -215,7 +742,40 @@ impl From<u8> for Address {
 /// Compute device address from address bits where bits are not floating
 impl From<(bool, bool, bool)> for Address {
     fn from(a: (bool, bool, bool)) -> Self {
-        Address(DEVICE_BASE_ADDRESS | ((a.0 as u8) << 2) | ((a.1 as u8) << 1) | a.2 as u8)
+        Address::from_pins(a.0, a.1, a.2)
+    }
+}
+
+/// Level of a tri-state address pin
+///
+/// The PCT2075 can distinguish floating address pins, which results in 27
+/// addresses. See `From<(PinLevel, PinLevel, PinLevel)> for Address`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PinLevel {
+    /// Connected to ground
+    Low,
+    /// Connected to the supply voltage
+    High,
+    /// Not connected
+    Float,
+}
+
+/// Compute the PCT2075 device address from the levels of the address pins
+/// `(A2, A1, A0)`.
+///
+/// Without floating pins, the address is the same as for the LM75.
+///
+/// ```
+/// use lm75::{Address, PinLevel::*};
+///
+/// assert_eq!(Address::from((false, true, false)), Address::from((Low, High, Low)));
+/// assert_eq!(Address::from(0x37), Address::from((Float, Float, Float)));
+/// ```
+impl From<(PinLevel, PinLevel, PinLevel)> for Address {
+    fn from(a: (PinLevel, PinLevel, PinLevel)) -> Self {
+        Address::from_pin_levels(a.0, a.1, a.2)
     }
 }
 
@@ -223,6 +783,8 @@ impl From<(bool, bool, bool)> for Address {
 ///
 /// Number of consecutive faults necessary to trigger OS condition.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FaultQueue {
     /// 1 fault will trigger OS condition (default)
     #[default]
@@ -235,8 +797,121 @@ pub enum FaultQueue {
     _6,
 }
 
+/// Temperature conversion resolution
+///
+/// A higher resolution results in a longer conversion time.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Resolution {
+    /// 9-bit resolution, 0.5ºC (default)
+    #[default]
+    Bits9,
+    /// 10-bit resolution, 0.25ºC
+    Bits10,
+    /// 11-bit resolution, 0.125ºC
+    Bits11,
+    /// 12-bit resolution, 0.0625ºC
+    Bits12,
+}
+
+/// Device variant selected at runtime
+///
+/// Used together with `Lm75::new_dynamic()` when the populated sensor is
+/// only known at runtime, for example after probing it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Variant {
+    /// LM75 (default)
+    #[default]
+    Lm75,
+    /// NXP LM75A/LM75B
+    #[cfg(feature = "lm75a")]
+    Lm75a,
+    /// PCT2075
+    #[cfg(feature = "pct2075")]
+    Pct2075,
+    /// G751
+    #[cfg(feature = "g751")]
+    G751,
+    /// MCP9800/1/2/3
+    #[cfg(feature = "mcp980x")]
+    Mcp980x,
+    /// DS7505
+    #[cfg(feature = "ds7505")]
+    Ds7505,
+    /// AT30TS75A
+    #[cfg(feature = "at30ts75a")]
+    At30ts75a,
+    /// MAX7500/1/2/3/4
+    #[cfg(feature = "max750x")]
+    Max750x,
+    /// MAX6625
+    #[cfg(feature = "max6625")]
+    Max6625,
+    /// MAX6626
+    #[cfg(feature = "max6626")]
+    Max6626,
+    /// TCN75A
+    #[cfg(feature = "tcn75a")]
+    Tcn75a,
+    /// TMP75/TMP175/TMP275
+    #[cfg(feature = "tmp75")]
+    Tmp75,
+    /// TMP1075
+    #[cfg(feature = "tmp1075")]
+    Tmp1075,
+    /// STDS75
+    #[cfg(feature = "stds75")]
+    Stds75,
+    /// STLM75
+    #[cfg(feature = "stlm75")]
+    Stlm75,
+    /// SE95
+    #[cfg(feature = "se95")]
+    Se95,
+    /// MAX31725/MAX31726
+    #[cfg(feature = "max3172x")]
+    Max3172x,
+}
+
+/// Power mode
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PowerMode {
+    /// Continuous conversions (default)
+    #[default]
+    Continuous,
+    /// Shutdown, no conversions
+    Shutdown,
+    /// Single conversion after which the device returns to shutdown
+    /// (MCP980x, TCN75A, TMP75, TMP1075 and MAX31725/6 only)
+    OneShot,
+}
+
+/// Power profile of devices with a sample rate register (PCT2075)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PowerProfile {
+    /// Normal operation with the default 100ms sample rate period (default)
+    #[default]
+    Normal,
+    /// Normal operation sampling with the given sample rate period
+    LowPower {
+        /// Sample rate period (ms) in 100ms increments up to 3100ms
+        period_ms: u16,
+    },
+    /// Shutdown keeping the sample rate period
+    Shutdown,
+}
+
 /// OS polarity
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OsPolarity {
     /// Active low (default)
     #[default]
@@ -247,6 +922,8 @@ pub enum OsPolarity {
 
 /// OS operation mode
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OsMode {
     /// Comparator (default)
     #[default]
@@ -255,10 +932,391 @@ pub enum OsMode {
     Interrupt,
 }
 
+/// Event signaled by the OS output
+///
+/// See `InterruptMode::acknowledge_alert()` and `AlertMonitor::poll()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AlertEvent {
+    /// The temperature rose above the OS temperature
+    OverTemperature,
+    /// The temperature fell below the hysteresis temperature
+    BelowHysteresis,
+}
+
+/// Outcome of waiting for an alert
+///
+/// See `AlertMonitor::wait_for_alert()` and `Lm75::poll_for_alert()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WaitOutcome {
+    /// The alert became active
+    Alert,
+    /// The timeout expired without an alert
+    Timeout,
+}
+
+/// Conversion rate (TMP1075 only)
+///
+/// Period between temperature conversions in continuous mode.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConversionRate {
+    /// 27.5ms (default)
+    #[default]
+    Ms27_5,
+    /// 55ms
+    Ms55,
+    /// 110ms
+    Ms110,
+    /// 220ms
+    Ms220,
+}
+
+/// Device register
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Register {
+    /// Temperature register
+    Temperature,
+    /// Configuration register
+    Configuration,
+    /// Hysteresis temperature register (THYST)
+    THyst,
+    /// Overtemperature shutdown register (TOS)
+    TOs,
+    /// Sample period register (TIDLE, PCT2075 only)
+    TIdle,
+    /// Any other register given by its pointer value (e.g. vendor-specific)
+    Other(u8),
+}
+
+impl Register {
+    pub(crate) fn addr(self) -> u8 {
+        match self {
+            Register::Temperature => 0x00,
+            Register::Configuration => 0x01,
+            Register::THyst => 0x02,
+            Register::TOs => 0x03,
+            Register::TIdle => 0x04,
+            Register::Other(addr) => addr,
+        }
+    }
+}
+
+/// Complete device settings
+///
+/// The default values correspond to the power-on defaults of the device.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Settings {
+    /// Whether the device is shut down
+    pub shutdown: bool,
+    /// Fault queue
+    pub fault_queue: FaultQueue,
+    /// OS polarity
+    pub os_polarity: OsPolarity,
+    /// OS operation mode
+    pub os_mode: OsMode,
+    /// OS temperature (celsius)
+    pub os_temperature: f32,
+    /// Hysteresis temperature (celsius)
+    pub hysteresis_temperature: f32,
+    /// Sample rate period in milliseconds (PCT2075 only).
+    ///
+    /// If `None`, the sample rate is not modified.
+    pub sample_rate: Option<u16>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            shutdown: false,
+            fault_queue: FaultQueue::default(),
+            os_polarity: OsPolarity::default(),
+            os_mode: OsMode::default(),
+            os_temperature: 80.0,
+            hysteresis_temperature: 75.0,
+            sample_rate: None,
+        }
+    }
+}
+
+/// Alert (OS output) configuration
+///
+/// See `Lm75::configure_alert()`. The default values correspond to the
+/// power-on defaults of the device.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlertConfig {
+    /// OS temperature (celsius)
+    pub os_temperature: f32,
+    /// Hysteresis temperature (celsius)
+    pub hysteresis_temperature: f32,
+    /// Fault queue
+    pub fault_queue: FaultQueue,
+    /// OS polarity
+    pub os_polarity: OsPolarity,
+    /// OS operation mode
+    pub os_mode: OsMode,
+}
+
+impl Default for AlertConfig {
+    fn default() -> Self {
+        AlertConfig {
+            os_temperature: 80.0,
+            hysteresis_temperature: 75.0,
+            fault_queue: FaultQueue::default(),
+            os_polarity: OsPolarity::default(),
+            os_mode: OsMode::default(),
+        }
+    }
+}
+
+/// Raw contents of the device registers
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RegisterDump {
+    /// Temperature register (MSB, LSB)
+    pub temperature: [u8; 2],
+    /// Configuration register
+    pub configuration: u8,
+    /// Hysteresis temperature register (MSB, LSB)
+    pub t_hyst: [u8; 2],
+    /// Overtemperature shutdown register (MSB, LSB)
+    pub t_os: [u8; 2],
+    /// Sample period register, if present on the device (PCT2075 only)
+    pub t_idle: Option<u8>,
+}
+
+/// Snapshot of the configured device registers
+///
+/// This can be obtained with `save_state()` and programmed back into the
+/// device with `restore_state()`. It can be stored as bytes, for example
+/// in flash memory, with `to_bytes()` and `from_bytes()`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceState {
+    /// Configuration register
+    pub config: Config,
+    /// Hysteresis temperature register (MSB, LSB)
+    pub t_hyst: [u8; 2],
+    /// Overtemperature shutdown register (MSB, LSB)
+    pub t_os: [u8; 2],
+    /// Sample period register, if present on the device (PCT2075 only)
+    pub t_idle: Option<u8>,
+}
+
+impl DeviceState {
+    /// Size of the byte representation of the state.
+    pub const SIZE: usize = 7;
+
+    /// Convert the state into bytes.
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        [
+            self.config.bits,
+            self.t_hyst[0],
+            self.t_hyst[1],
+            self.t_os[0],
+            self.t_os[1],
+            u8::from(self.t_idle.is_some()),
+            self.t_idle.unwrap_or_default(),
+        ]
+    }
+
+    /// Create the state from bytes obtained with `to_bytes()`.
+    pub fn from_bytes(bytes: &[u8; Self::SIZE]) -> Self {
+        DeviceState {
+            config: Config { bits: bytes[0] },
+            t_hyst: [bytes[1], bytes[2]],
+            t_os: [bytes[3], bytes[4]],
+            t_idle: if bytes[5] != 0 { Some(bytes[6]) } else { None },
+        }
+    }
+}
+
+/// Configuration register update mode
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConfigUpdate {
+    /// Modify the locally cached configuration and write it (default)
+    #[default]
+    Cached,
+    /// Read the configuration from the device, modify it and write it back.
+    ///
+    /// Use this if the configuration register may be modified by someone
+    /// else, for example another bus master or a bootloader.
+    ReadModifyWrite,
+}
+
+/// Rounding of temperatures to the device resolution
+///
+/// See `Lm75::with_rounding()`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Rounding {
+    /// Round towards zero (default), e.g. 2.6ºC becomes 2.5ºC with 9-bit resolution
+    #[default]
+    Truncate,
+    /// Round to the nearest value, halfway cases away from zero,
+    /// e.g. 2.8ºC becomes 3.0ºC with 9-bit resolution
+    Nearest,
+}
+
+/// Description of the capabilities of a device
+///
+/// See `Lm75::capabilities()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Capabilities {
+    /// Resolution of the temperature readings in bits
+    pub resolution_bits: u8,
+    /// Whether the sample rate can be set
+    pub has_sample_rate: bool,
+    /// Whether one-shot conversions are supported
+    pub has_one_shot: bool,
+    /// Valid range of the OS and hysteresis temperatures (celsius)
+    /// as inclusive minimum and maximum
+    pub temperature_range: (i16, i16),
+}
+
+/// Temperature measurement together with the device shutdown state
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Measurement {
+    /// Temperature (celsius)
+    pub temperature: f32,
+    /// Whether the device was shut down when the temperature was read.
+    ///
+    /// If so, the temperature corresponds to the last conversion done
+    /// before entering shutdown and may be stale.
+    pub shutdown: bool,
+    /// Temperature resolution of the device (celsius)
+    pub resolution: f32,
+}
+
+/// Aggregate temperature of a sensor group
+///
+/// See `Lm75Group::read_all()`.
+#[derive(Debug)]
+pub struct GroupReading<E> {
+    /// Lowest temperature (celsius), if any sensor could be read
+    pub min: Option<f32>,
+    /// Highest temperature (celsius), if any sensor could be read
+    pub max: Option<f32>,
+    /// Mean temperature (celsius), if any sensor could be read
+    pub mean: Option<f32>,
+    /// Number of sensors read successfully
+    pub count: usize,
+    /// Error of each sensor which could not be read, at its index
+    pub errors: [Option<Error<E>>; GROUP_CAPACITY],
+}
+
+/// Print the temperature with one decimal for a resolution of 0.5ºC and
+/// two decimals for finer resolutions unless a precision is given,
+/// e.g. `25.5°C` or `25.25°C`.
+impl core::fmt::Display for Measurement {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let decimals = f
+            .precision()
+            .unwrap_or_else(|| conversion::convert_resolution_to_display_decimals(self.resolution));
+        write!(f, "{:.*}°C", decimals, self.temperature)?;
+        if self.shutdown {
+            f.write_str(" (shutdown)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Generic temperature sensor
+///
+/// This allows writing application code against any temperature sensor
+/// implementing this trait instead of a concrete driver.
+///
+/// ```
+/// use lm75::TemperatureSensor;
+///
+/// fn is_too_hot<S: TemperatureSensor>(sensor: &mut S) -> Result<bool, S::Error> {
+///     Ok(sensor.read_temperature()? > 70.0)
+/// }
+/// ```
+pub trait TemperatureSensor {
+    /// Error type
+    type Error;
+
+    /// Read the temperature (celsius).
+    fn read_temperature(&mut self) -> Result<f32, Self::Error>;
+}
+
+/// Common interface of all LM75 device variants
+///
+/// This trait is object safe so that drivers for different devices can be
+/// used together at runtime, for example in an array.
+///
+/// ```
+/// use lm75::{Lm75Like, OsPolarity};
+///
+/// fn set_polarity<E>(sensors: &mut [&mut dyn Lm75Like<Error = E>]) -> Result<(), E> {
+///     for sensor in sensors.iter_mut() {
+///         sensor.set_os_polarity(OsPolarity::ActiveHigh)?;
+///     }
+///     Ok(())
+/// }
+/// ```
+pub trait Lm75Like: TemperatureSensor {
+    /// Read the raw temperature register value.
+    fn read_temperature_raw(&mut self) -> Result<i16, Self::Error>;
+
+    /// Read the temperature together with the shutdown state.
+    fn read_measurement(&mut self) -> Result<Measurement, Self::Error>;
+
+    /// Temperature step of the device (celsius).
+    fn resolution(&self) -> f32;
+
+    /// Describe the capabilities of the device.
+    fn capabilities(&self) -> Capabilities;
+
+    /// Enable the sensor.
+    fn enable(&mut self) -> Result<(), Self::Error>;
+
+    /// Disable the sensor (shutdown).
+    fn disable(&mut self) -> Result<(), Self::Error>;
+
+    /// Set the fault queue.
+    fn set_fault_queue(&mut self, fq: FaultQueue) -> Result<(), Self::Error>;
+
+    /// Set the OS polarity.
+    fn set_os_polarity(&mut self, polarity: OsPolarity) -> Result<(), Self::Error>;
+
+    /// Set the OS operation mode.
+    fn set_os_mode(&mut self, mode: OsMode) -> Result<(), Self::Error>;
+
+    /// Set the OS temperature (celsius).
+    fn set_os_temperature(&mut self, temperature: f32) -> Result<(), Self::Error>;
+
+    /// Set the hysteresis temperature (celsius).
+    fn set_hysteresis_temperature(&mut self, temperature: f32) -> Result<(), Self::Error>;
+
+    /// Apply all settings at once.
+    fn apply_settings(&mut self, settings: &Settings) -> Result<(), Self::Error>;
+}
+
 const DEVICE_BASE_ADDRESS: u8 = 0b100_1000;
 
-#[derive(Debug, Default, Clone, Copy)]
-struct Config {
+/// Configuration register contents
+///
+/// The default value corresponds to the power-on default of the device.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Config {
     bits: u8,
 }
 
@@ -275,16 +1333,120 @@ impl Config {
     }
 }
 
-/// IC Markers
+/// IC Markers and capability traits
+///
+/// The functionality available for a device is determined by the capability
+/// traits its marker implements. Further devices can be supported by
+/// implementing these traits for a custom marker and creating the driver
+/// with `Lm75::new_custom()`.
 pub mod ic {
+    pub use crate::markers::{
+        HasOneShot, HasProductId, HasResolutionConfig, HasSampleRate, RegisterSupport,
+        ResolutionSupport,
+    };
+
     /// LM75 Marker
+    #[derive(Debug, Default, Clone, Copy)]
     pub struct Lm75;
 
     /// PCT2075 Marker
+    #[cfg(feature = "pct2075")]
+    #[derive(Debug, Default, Clone, Copy)]
     pub struct Pct2075;
+
+    /// NXP LM75A/LM75B Marker
+    #[cfg(feature = "lm75a")]
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Lm75a;
+
+    /// G751 Marker
+    #[cfg(feature = "g751")]
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct G751;
+
+    /// MCP9800/1/2/3 Marker
+    #[cfg(feature = "mcp980x")]
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Mcp980x;
+
+    /// DS7505 Marker
+    #[cfg(feature = "ds7505")]
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Ds7505;
+
+    /// AT30TS75A Marker
+    #[cfg(feature = "at30ts75a")]
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct At30ts75a;
+
+    /// MAX7500/1/2/3/4 Marker
+    #[cfg(feature = "max750x")]
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Max750x;
+
+    /// MAX6625 Marker
+    #[cfg(feature = "max6625")]
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Max6625;
+
+    /// MAX6626 Marker
+    #[cfg(feature = "max6626")]
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Max6626;
+
+    /// TCN75A Marker
+    #[cfg(feature = "tcn75a")]
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Tcn75a;
+
+    /// TMP75/TMP175/TMP275 Marker
+    #[cfg(feature = "tmp75")]
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Tmp75;
+
+    /// TMP1075 Marker
+    #[cfg(feature = "tmp1075")]
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Tmp1075;
+
+    /// STDS75 Marker
+    #[cfg(feature = "stds75")]
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Stds75;
+
+    /// STLM75 Marker
+    #[cfg(feature = "stlm75")]
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Stlm75;
+
+    /// SE95 Marker
+    #[cfg(feature = "se95")]
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Se95;
+
+    /// MAX31725/MAX31726 Marker
+    #[cfg(feature = "max3172x")]
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Max3172x;
+
+    /// Runtime-selected device Marker
+    ///
+    /// See [`Variant`](crate::Variant).
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Dynamic(pub(crate) crate::Variant);
+
+    /// Generic LM75-compatible device Marker with a fixed resolution
+    ///
+    /// See `Lm75::new_generic()`.
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Generic(pub(crate) crate::Resolution);
 }
 
 /// LM75 device driver.
+///
+/// `Lm75Async` offers the same interface asynchronously. This requires the
+/// `async` feature.
+#[maybe_async_cfg::maybe(sync(keep_self), async(feature = "async"))]
 #[derive(Debug, Default)]
 pub struct Lm75<I2C, IC> {
     /// The concrete I²C device implementation.
@@ -293,30 +1455,254 @@ pub struct Lm75<I2C, IC> {
     address: u8,
     /// Configuration register status.
     config: Config,
+    /// Configuration register update mode.
+    config_update: ConfigUpdate,
+    /// Rounding of the temperatures written.
+    rounding: Rounding,
+    /// Whether to read back every register write.
+    verify_writes: bool,
+    /// Whether to check that temperature readings are plausible.
+    check_readings: bool,
+    /// Register the device register pointer is known to be set to.
+    pointer: Option<Register>,
+    /// Whether to issue a stop condition between the transfers of a transaction.
+    separate_transfers: bool,
+    /// Whether a one-shot conversion was started and its result was not read yet.
+    one_shot_started: bool,
     /// Device Marker
-    _ic: PhantomData<IC>,
+    ic: IC,
 }
 
-mod conversion;
-mod device_impl;
-mod markers;
+/// LM75 device driver shutting the device down between temperature readings.
+///
+/// Every `read_temperature()` wakes the device up or triggers a one-shot
+/// conversion, waits for the conversion time and leaves the device in
+/// shutdown afterwards. Create it with `Lm75::with_auto_shutdown()`.
+///
+/// `AutoShutdownAsync` offers the same interface asynchronously. This
+/// requires the `async` feature.
+#[maybe_async_cfg::maybe(sync(keep_self), async(feature = "async"), idents(Lm75(sync)))]
+#[derive(Debug)]
+pub struct AutoShutdown<I2C, IC, D> {
+    /// The device driver.
+    sensor: Lm75<I2C, IC>,
+    /// The delay used to wait for the conversions.
+    delay: D,
+}
 
-/// Private Module
-pub mod private {
-    use crate::ic;
+/// Duty-cycled temperature sampling.
+///
+/// Every `sample()` waits until the sampling period has elapsed, reads the
+/// temperature like `read_temperature_one_shot()` and leaves the device in
+/// shutdown until the next sample. Create it with `Lm75::with_duty_cycle()`.
+///
+/// `SamplerAsync` offers the same interface asynchronously. This requires
+/// the `async` feature.
+#[maybe_async_cfg::maybe(sync(keep_self), async(feature = "async"), idents(Lm75(sync)))]
+#[derive(Debug)]
+pub struct Sampler<I2C, IC, D> {
+    /// The device driver.
+    sensor: Lm75<I2C, IC>,
+    /// The delay used to wait for the conversions and between samples.
+    delay: D,
+    /// Sampling period (ms).
+    period_ms: u32,
+    /// Time the device is awake for each sample (ms), once known.
+    awake_ms: Option<u32>,
+    /// Whether a sample has already been taken.
+    started: bool,
+}
 
-    /// Sealed Traits
-    pub trait Sealed {}
+/// LM75 device driver together with the pin connected to the OS output.
+///
+/// The pin level is interpreted according to the OS polarity configured
+/// through the driver, so that both cannot get out of sync.
+/// Create it with `Lm75::with_os_pin()`.
+///
+/// `AlertMonitorAsync` offers the same interface asynchronously. This
+/// requires the `async` feature.
+#[maybe_async_cfg::maybe(sync(keep_self), async(feature = "async"), idents(Lm75(sync)))]
+#[derive(Debug)]
+pub struct AlertMonitor<I2C, IC, P> {
+    /// The device driver.
+    sensor: Lm75<I2C, IC>,
+    /// The input pin connected to the OS output.
+    os_pin: P,
+    /// Consecutive consistent pin samples required to change the state.
+    debounce_samples: u8,
+    /// Debounced state of the OS output.
+    pin_active: bool,
+    /// Consecutive samples disagreeing with the debounced state.
+    pending_samples: u8,
+    /// Whether the OS output was active in any sample since the latch was
+    /// cleared.
+    latched: bool,
+    /// Whether the OS output was active when last polled.
+    alert_active: bool,
+    /// Whether the last event reported by polling was an over-temperature.
+    over_temperature: bool,
+}
 
-    impl Sealed for ic::Lm75 {}
+/// LM75 device driver with the OS output in interrupt mode.
+///
+/// Methods which only make sense in interrupt mode, like
+/// `acknowledge_alert()`, are only available on this type so that misuse
+/// is caught at compile time. The OS operation mode cannot be changed
+/// through it. Create it with `Lm75::into_interrupt_mode()` and go back
+/// with `into_comparator_mode()`.
+///
+/// `InterruptModeAsync` offers the same interface asynchronously. This
+/// requires the `async` feature.
+#[maybe_async_cfg::maybe(sync(keep_self), async(feature = "async"), idents(Lm75(sync)))]
+#[derive(Debug)]
+pub struct InterruptMode<I2C, IC> {
+    /// The device driver.
+    sensor: Lm75<I2C, IC>,
+}
 
-    impl Sealed for ic::Pct2075 {}
+/// Group of up to 8 LM75 devices, e.g. on the same bus.
+///
+/// The sensors are addressed by the index in which they were added.
+/// Reading all sensors reports the result of each of them separately so
+/// that a failing sensor does not hide the readings of the others.
+/// Create it with `Lm75Group::new()` and add sensors with `add()`.
+///
+/// `Lm75GroupAsync` offers the same interface asynchronously. This
+/// requires the `async` feature.
+#[maybe_async_cfg::maybe(sync(keep_self), async(feature = "async"), idents(Lm75(sync)))]
+#[derive(Debug)]
+pub struct Lm75Group<I2C, IC> {
+    /// The device drivers.
+    sensors: [Option<Lm75<I2C, IC>>; GROUP_CAPACITY],
 }
 
+/// Maximum number of sensors in a `Lm75Group`
+const GROUP_CAPACITY: usize = 8;
+
+mod conversion;
+mod device_impl;
+#[cfg(feature = "eh0")]
+pub mod eh0;
+mod markers;
+mod software_os;
+pub use software_os::SoftwareOs;
+mod temperature;
+pub use temperature::{Celsius, IntoTemperature, Millicelsius, RawTemperature, Temperature};
+mod window_alarm;
+pub use window_alarm::{WindowAlarm, WindowState};
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn can_modify_config() {
+        let config = Config::default()
+            .with_shutdown(true)
+            .with_fault_queue(FaultQueue::_6)
+            .with_os_polarity(OsPolarity::ActiveHigh)
+            .with_os_mode(OsMode::Interrupt);
+        assert_eq!(0b0001_1111, config.bits);
+        assert!(config.is_shutdown());
+        assert_eq!(FaultQueue::_6, config.fault_queue());
+        assert_eq!(OsPolarity::ActiveHigh, config.os_polarity());
+        assert_eq!(OsMode::Interrupt, config.os_mode());
+    }
+
+    #[test]
+    fn can_create_checked_address() {
+        assert_eq!(Ok(Address(0x08)), Address::new(0x08));
+        assert_eq!(Ok(Address(0x4F)), Address::new(0x4F));
+        assert_eq!(Ok(Address(0x77)), Address::new(0x77));
+        assert_eq!(Err(AddressError::Reserved), Address::new(0x07));
+        assert_eq!(Err(AddressError::Reserved), Address::new(0x78));
+        assert_eq!(Err(AddressError::Reserved), Address::new(0x7F));
+        assert_eq!(Err(AddressError::TooLarge), Address::new(0x80));
+        assert_eq!(Err(AddressError::TooLarge), Address::new(0xFF));
+    }
+
+    #[test]
+    fn can_iterate_address_ranges() {
+        assert!(Address::lm75_range().eq((0x48..=0x4F).map(Address)));
+        assert!(Address::pct2075_range().all(|a| Address::new(a.0).is_ok()));
+        assert!(Address::pct2075_range()
+            .zip(Address::pct2075_range().skip(1))
+            .all(|(a, b)| a < b));
+        assert!(Address::lm75_range().all(|a| Address::pct2075_range().any(|b| a == b)));
+    }
+
+    #[test]
+    fn named_addresses_match_pin_combinations() {
+        let named = [
+            Address::A0,
+            Address::A1,
+            Address::A2,
+            Address::A3,
+            Address::A4,
+            Address::A5,
+            Address::A6,
+            Address::A7,
+        ];
+        assert!(named.into_iter().eq(Address::lm75_range()));
+        assert_eq!(Address::default(), Address::DEFAULT);
+        assert_eq!(Address::A0, Address::DEFAULT);
+    }
+
+    #[test]
+    fn can_compute_address_at_compile_time() {
+        const PINS: Address = Address::from_pins(true, false, true);
+        const LEVELS: Address =
+            Address::from_pin_levels(PinLevel::Float, PinLevel::High, PinLevel::Float);
+        assert_eq!(Address(0x4D), PINS);
+        assert_eq!(Address::from((true, false, true)), PINS);
+        assert_eq!(Address(0x2D), LEVELS);
+    }
+
+    #[test]
+    fn pin_levels_cover_pct2075_addresses() {
+        use PinLevel::*;
+        let levels = [Low, High, Float];
+        let mut addresses = [0; 27];
+        let mut i = 0;
+        for a2 in levels {
+            for a1 in levels {
+                for a0 in levels {
+                    addresses[i] = Address::from((a2, a1, a0)).0;
+                    i += 1;
+                }
+            }
+        }
+        addresses.sort_unstable();
+        assert_eq!(PCT2075_ADDRESSES, addresses);
+    }
+
+    #[test]
+    fn default_config_matches_power_on_defaults() {
+        let config = Config::default();
+        assert!(!config.is_shutdown());
+        assert_eq!(FaultQueue::_1, config.fault_queue());
+        assert_eq!(OsPolarity::ActiveLow, config.os_polarity());
+        assert_eq!(OsMode::Comparator, config.os_mode());
+    }
+
+    #[test]
+    fn can_convert_device_state_to_and_from_bytes() {
+        let state = DeviceState {
+            config: Config { bits: 0b0001_0110 },
+            t_hyst: [0x4B, 0x80],
+            t_os: [0x50, 0x00],
+            t_idle: Some(0x0F),
+        };
+        let bytes = state.to_bytes();
+        assert_eq!([0b0001_0110, 0x4B, 0x80, 0x50, 0x00, 1, 0x0F], bytes);
+        assert_eq!(state, DeviceState::from_bytes(&bytes));
+        let state = DeviceState {
+            t_idle: None,
+            ..state
+        };
+        assert_eq!(state, DeviceState::from_bytes(&state.to_bytes()));
+    }
+
     #[test]
     fn default_address_matches_alternative_all_false() {
         assert_eq!(Address::default(), Address::from((false, false, false)))
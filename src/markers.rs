@@ -1,26 +1,811 @@
-use crate::{ic, private};
+use crate::{conversion, ic, Config, Variant};
 
 pub struct BitMasks;
 
+// Some masks are only used by the devices enabled through cargo features.
+#[allow(dead_code)]
 impl BitMasks {
     pub const RESOLUTION_9BIT: u16 = 0b1111_1111_1000_0000;
+    pub const RESOLUTION_10BIT: u16 = 0b1111_1111_1100_0000;
     pub const RESOLUTION_11BIT: u16 = 0b1111_1111_1110_0000;
+    pub const RESOLUTION_12BIT: u16 = 0b1111_1111_1111_0000;
+    pub const RESOLUTION_13BIT: u16 = 0b1111_1111_1111_1000;
+    pub const RESOLUTION_16BIT: u16 = 0b1111_1111_1111_1111;
     pub const SAMPLE_RATE_MASK: u8 = 0b0001_1111;
 }
 
-#[doc(hidden)]
-pub trait ResolutionSupport<E>: private::Sealed {
-    fn get_resolution_mask() -> u16;
+impl ic::Lm75 {
+    /// Maximum temperature conversion time (ms).
+    pub const CONVERSION_TIME_MS: u32 = 300;
+
+    /// Typical supply current (µA) in continuous operation and in shutdown.
+    pub const SUPPLY_CURRENT_UA: (f32, f32) = (250.0, 1.0);
+}
+
+#[cfg(feature = "pct2075")]
+impl ic::Pct2075 {
+    /// Maximum temperature conversion time (ms).
+    pub const CONVERSION_TIME_MS: u32 = 28;
+
+    /// Typical supply current (µA) in continuous operation and in shutdown.
+    ///
+    /// The continuous operation figure corresponds to the default sample rate
+    /// of 100ms. `estimate_supply_current_ua()` scales it to the configured
+    /// sample rate.
+    pub const SUPPLY_CURRENT_UA: (f32, f32) = (75.0, 0.1);
+}
+
+#[cfg(feature = "lm75a")]
+impl ic::Lm75a {
+    /// Maximum temperature conversion time (ms).
+    pub const CONVERSION_TIME_MS: u32 = 100;
+
+    /// Typical supply current (µA) in continuous operation and in shutdown.
+    pub const SUPPLY_CURRENT_UA: (f32, f32) = (100.0, 1.0);
+}
+
+#[cfg(feature = "g751")]
+impl ic::G751 {
+    /// Maximum temperature conversion time (ms).
+    pub const CONVERSION_TIME_MS: u32 = 300;
+
+    /// Typical supply current (µA) in continuous operation and in shutdown.
+    pub const SUPPLY_CURRENT_UA: (f32, f32) = (250.0, 1.0);
+}
+
+#[cfg(feature = "mcp980x")]
+impl ic::Mcp980x {
+    /// Maximum temperature conversion time (ms) for each `Resolution`,
+    /// from 9 to 12 bits.
+    pub const CONVERSION_TIME_MS: [u32; 4] = [75, 150, 300, 600];
+
+    /// Typical supply current (µA) in continuous operation and in shutdown.
+    pub const SUPPLY_CURRENT_UA: (f32, f32) = (200.0, 1.0);
+}
+
+#[cfg(feature = "ds7505")]
+impl ic::Ds7505 {
+    /// Maximum temperature conversion time (ms) for each `Resolution`,
+    /// from 9 to 12 bits.
+    pub const CONVERSION_TIME_MS: [u32; 4] = [25, 50, 100, 200];
+
+    /// Typical supply current (µA) in continuous operation and in shutdown.
+    pub const SUPPLY_CURRENT_UA: (f32, f32) = (750.0, 1.0);
+}
+
+#[cfg(feature = "at30ts75a")]
+impl ic::At30ts75a {
+    /// Maximum temperature conversion time (ms) for each `Resolution`,
+    /// from 9 to 12 bits.
+    pub const CONVERSION_TIME_MS: [u32; 4] = [25, 50, 100, 200];
+
+    /// Typical supply current (µA) in continuous operation and in shutdown.
+    pub const SUPPLY_CURRENT_UA: (f32, f32) = (65.0, 2.0);
+}
+
+#[cfg(feature = "max750x")]
+impl ic::Max750x {
+    /// Maximum temperature conversion time (ms).
+    pub const CONVERSION_TIME_MS: u32 = 150;
+
+    /// Typical supply current (µA) in continuous operation and in shutdown.
+    pub const SUPPLY_CURRENT_UA: (f32, f32) = (250.0, 3.0);
+}
+
+#[cfg(feature = "max6625")]
+impl ic::Max6625 {
+    /// Maximum temperature conversion time (ms).
+    pub const CONVERSION_TIME_MS: u32 = 200;
+
+    /// Typical supply current (µA) in continuous operation and in shutdown.
+    pub const SUPPLY_CURRENT_UA: (f32, f32) = (400.0, 1.0);
+}
+
+#[cfg(feature = "max6626")]
+impl ic::Max6626 {
+    /// Maximum temperature conversion time (ms).
+    pub const CONVERSION_TIME_MS: u32 = 200;
+
+    /// Typical supply current (µA) in continuous operation and in shutdown.
+    pub const SUPPLY_CURRENT_UA: (f32, f32) = (400.0, 1.0);
 }
 
-impl<E> ResolutionSupport<E> for ic::Pct2075 {
-    fn get_resolution_mask() -> u16 {
+#[cfg(feature = "tcn75a")]
+impl ic::Tcn75a {
+    /// Maximum temperature conversion time (ms) for each `Resolution`,
+    /// from 9 to 12 bits.
+    pub const CONVERSION_TIME_MS: [u32; 4] = [75, 150, 300, 600];
+
+    /// Typical supply current (µA) in continuous operation and in shutdown.
+    pub const SUPPLY_CURRENT_UA: (f32, f32) = (200.0, 1.0);
+}
+
+#[cfg(feature = "tmp75")]
+impl ic::Tmp75 {
+    /// Maximum temperature conversion time (ms) for each `Resolution`,
+    /// from 9 to 12 bits.
+    pub const CONVERSION_TIME_MS: [u32; 4] = [38, 75, 150, 300];
+
+    /// Typical supply current (µA) in continuous operation and in shutdown.
+    pub const SUPPLY_CURRENT_UA: (f32, f32) = (50.0, 0.1);
+}
+
+#[cfg(feature = "tmp1075")]
+impl ic::Tmp1075 {
+    /// Maximum temperature conversion time (ms).
+    pub const CONVERSION_TIME_MS: u32 = 10;
+
+    /// Typical supply current (µA) in continuous operation and in shutdown.
+    pub const SUPPLY_CURRENT_UA: (f32, f32) = (40.0, 0.5);
+}
+
+#[cfg(feature = "stds75")]
+impl ic::Stds75 {
+    /// Maximum temperature conversion time (ms) for each `Resolution`,
+    /// from 9 to 12 bits.
+    pub const CONVERSION_TIME_MS: [u32; 4] = [150, 300, 600, 1200];
+
+    /// Typical supply current (µA) in continuous operation and in shutdown.
+    pub const SUPPLY_CURRENT_UA: (f32, f32) = (125.0, 1.0);
+}
+
+#[cfg(feature = "stlm75")]
+impl ic::Stlm75 {
+    /// Maximum temperature conversion time (ms).
+    pub const CONVERSION_TIME_MS: u32 = 150;
+
+    /// Typical supply current (µA) in continuous operation and in shutdown.
+    pub const SUPPLY_CURRENT_UA: (f32, f32) = (125.0, 1.0);
+}
+
+#[cfg(feature = "se95")]
+impl ic::Se95 {
+    /// Maximum temperature conversion time (ms).
+    pub const CONVERSION_TIME_MS: u32 = 100;
+
+    /// Typical supply current (µA) in continuous operation and in shutdown.
+    pub const SUPPLY_CURRENT_UA: (f32, f32) = (250.0, 1.0);
+}
+
+#[cfg(feature = "max3172x")]
+impl ic::Max3172x {
+    /// Maximum temperature conversion time (ms).
+    pub const CONVERSION_TIME_MS: u32 = 50;
+
+    /// Typical supply current (µA) in continuous operation and in shutdown.
+    pub const SUPPLY_CURRENT_UA: (f32, f32) = (600.0, 0.4);
+}
+
+/// Temperature register encoding of a device.
+///
+/// All devices must implement this trait.
+pub trait ResolutionSupport {
+    /// Mask of the valid bits of the temperature register for the given configuration.
+    fn get_resolution_mask(&self, config: Config) -> u16;
+
+    /// Mask of the valid bits of the OS and hysteresis temperature registers.
+    ///
+    /// This is the same as the temperature register mask by default.
+    fn get_limit_mask(&self, config: Config) -> u16 {
+        self.get_resolution_mask(config)
+    }
+
+    /// Offset (celsius) of the temperature register encoding.
+    ///
+    /// This is zero by default.
+    fn get_temperature_offset(&self, _: Config) -> i16 {
+        0
+    }
+
+    /// Operating temperature range (celsius) as inclusive minimum and maximum.
+    ///
+    /// This is `[-55, 125]` by default.
+    fn get_temperature_range(&self, _: Config) -> (i16, i16) {
+        (-55, 125)
+    }
+
+    /// Maximum temperature conversion time (ms) for the given configuration.
+    ///
+    /// This is 300ms like for the LM75 by default.
+    fn get_conversion_time_ms(&self, _: Config) -> u32 {
+        300
+    }
+
+    /// Typical supply current (µA) in continuous operation and in shutdown.
+    ///
+    /// This is 250µA and 1µA like for the LM75 by default.
+    fn get_supply_current_ua(&self) -> (f32, f32) {
+        (250.0, 1.0)
+    }
+}
+
+#[cfg(feature = "pct2075")]
+impl ResolutionSupport for ic::Pct2075 {
+    fn get_resolution_mask(&self, _: Config) -> u16 {
         BitMasks::RESOLUTION_11BIT
     }
+
+    fn get_conversion_time_ms(&self, _: Config) -> u32 {
+        Self::CONVERSION_TIME_MS
+    }
+
+    fn get_supply_current_ua(&self) -> (f32, f32) {
+        Self::SUPPLY_CURRENT_UA
+    }
+}
+
+impl ResolutionSupport for ic::Lm75 {
+    fn get_resolution_mask(&self, _: Config) -> u16 {
+        BitMasks::RESOLUTION_9BIT
+    }
+
+    fn get_conversion_time_ms(&self, _: Config) -> u32 {
+        Self::CONVERSION_TIME_MS
+    }
+
+    fn get_supply_current_ua(&self) -> (f32, f32) {
+        Self::SUPPLY_CURRENT_UA
+    }
+}
+
+#[cfg(feature = "max750x")]
+impl ResolutionSupport for ic::Max750x {
+    fn get_resolution_mask(&self, _: Config) -> u16 {
+        BitMasks::RESOLUTION_9BIT
+    }
+
+    fn get_conversion_time_ms(&self, _: Config) -> u32 {
+        Self::CONVERSION_TIME_MS
+    }
+
+    fn get_supply_current_ua(&self) -> (f32, f32) {
+        Self::SUPPLY_CURRENT_UA
+    }
+}
+
+#[cfg(feature = "max6625")]
+impl ResolutionSupport for ic::Max6625 {
+    fn get_resolution_mask(&self, _: Config) -> u16 {
+        BitMasks::RESOLUTION_9BIT
+    }
+
+    fn get_conversion_time_ms(&self, _: Config) -> u32 {
+        Self::CONVERSION_TIME_MS
+    }
+
+    fn get_supply_current_ua(&self) -> (f32, f32) {
+        Self::SUPPLY_CURRENT_UA
+    }
 }
 
-impl<E> ResolutionSupport<E> for ic::Lm75 {
-    fn get_resolution_mask() -> u16 {
+#[cfg(feature = "max6626")]
+impl ResolutionSupport for ic::Max6626 {
+    fn get_resolution_mask(&self, _: Config) -> u16 {
+        BitMasks::RESOLUTION_12BIT
+    }
+
+    fn get_conversion_time_ms(&self, _: Config) -> u32 {
+        Self::CONVERSION_TIME_MS
+    }
+
+    fn get_supply_current_ua(&self) -> (f32, f32) {
+        Self::SUPPLY_CURRENT_UA
+    }
+}
+
+#[cfg(feature = "ds7505")]
+impl ResolutionSupport for ic::Ds7505 {
+    fn get_resolution_mask(&self, config: Config) -> u16 {
+        conversion::convert_resolution_to_mask(config.resolution())
+    }
+
+    fn get_limit_mask(&self, _: Config) -> u16 {
         BitMasks::RESOLUTION_9BIT
     }
+
+    fn get_conversion_time_ms(&self, config: Config) -> u32 {
+        Self::CONVERSION_TIME_MS[config.resolution() as usize]
+    }
+
+    fn get_supply_current_ua(&self) -> (f32, f32) {
+        Self::SUPPLY_CURRENT_UA
+    }
 }
+
+#[cfg(feature = "at30ts75a")]
+impl ResolutionSupport for ic::At30ts75a {
+    fn get_resolution_mask(&self, config: Config) -> u16 {
+        conversion::convert_resolution_to_mask(config.resolution())
+    }
+
+    fn get_limit_mask(&self, _: Config) -> u16 {
+        BitMasks::RESOLUTION_9BIT
+    }
+
+    fn get_conversion_time_ms(&self, config: Config) -> u32 {
+        Self::CONVERSION_TIME_MS[config.resolution() as usize]
+    }
+
+    fn get_supply_current_ua(&self) -> (f32, f32) {
+        Self::SUPPLY_CURRENT_UA
+    }
+}
+
+#[cfg(feature = "tcn75a")]
+impl ResolutionSupport for ic::Tcn75a {
+    fn get_resolution_mask(&self, config: Config) -> u16 {
+        conversion::convert_resolution_to_mask(config.resolution())
+    }
+
+    fn get_limit_mask(&self, _: Config) -> u16 {
+        BitMasks::RESOLUTION_9BIT
+    }
+
+    fn get_conversion_time_ms(&self, config: Config) -> u32 {
+        Self::CONVERSION_TIME_MS[config.resolution() as usize]
+    }
+
+    fn get_supply_current_ua(&self) -> (f32, f32) {
+        Self::SUPPLY_CURRENT_UA
+    }
+}
+
+#[cfg(feature = "tmp75")]
+impl ResolutionSupport for ic::Tmp75 {
+    fn get_resolution_mask(&self, config: Config) -> u16 {
+        conversion::convert_resolution_to_mask(config.resolution())
+    }
+
+    fn get_limit_mask(&self, _: Config) -> u16 {
+        BitMasks::RESOLUTION_12BIT
+    }
+
+    fn get_temperature_range(&self, _: Config) -> (i16, i16) {
+        (-55, 127)
+    }
+
+    fn get_conversion_time_ms(&self, config: Config) -> u32 {
+        Self::CONVERSION_TIME_MS[config.resolution() as usize]
+    }
+
+    fn get_supply_current_ua(&self) -> (f32, f32) {
+        Self::SUPPLY_CURRENT_UA
+    }
+}
+
+#[cfg(feature = "tmp1075")]
+impl ResolutionSupport for ic::Tmp1075 {
+    fn get_resolution_mask(&self, _: Config) -> u16 {
+        BitMasks::RESOLUTION_12BIT
+    }
+
+    fn get_conversion_time_ms(&self, _: Config) -> u32 {
+        Self::CONVERSION_TIME_MS
+    }
+
+    fn get_supply_current_ua(&self) -> (f32, f32) {
+        Self::SUPPLY_CURRENT_UA
+    }
+}
+
+#[cfg(feature = "stds75")]
+impl ResolutionSupport for ic::Stds75 {
+    fn get_resolution_mask(&self, config: Config) -> u16 {
+        conversion::convert_resolution_to_mask(config.resolution())
+    }
+
+    fn get_limit_mask(&self, _: Config) -> u16 {
+        BitMasks::RESOLUTION_9BIT
+    }
+
+    fn get_conversion_time_ms(&self, config: Config) -> u32 {
+        Self::CONVERSION_TIME_MS[config.resolution() as usize]
+    }
+
+    fn get_supply_current_ua(&self) -> (f32, f32) {
+        Self::SUPPLY_CURRENT_UA
+    }
+}
+
+#[cfg(feature = "stlm75")]
+impl ResolutionSupport for ic::Stlm75 {
+    fn get_resolution_mask(&self, _: Config) -> u16 {
+        BitMasks::RESOLUTION_9BIT
+    }
+
+    fn get_conversion_time_ms(&self, _: Config) -> u32 {
+        Self::CONVERSION_TIME_MS
+    }
+
+    fn get_supply_current_ua(&self) -> (f32, f32) {
+        Self::SUPPLY_CURRENT_UA
+    }
+}
+
+#[cfg(feature = "se95")]
+impl ResolutionSupport for ic::Se95 {
+    fn get_resolution_mask(&self, _: Config) -> u16 {
+        BitMasks::RESOLUTION_13BIT
+    }
+
+    fn get_limit_mask(&self, _: Config) -> u16 {
+        BitMasks::RESOLUTION_9BIT
+    }
+
+    fn get_conversion_time_ms(&self, _: Config) -> u32 {
+        Self::CONVERSION_TIME_MS
+    }
+
+    fn get_supply_current_ua(&self) -> (f32, f32) {
+        Self::SUPPLY_CURRENT_UA
+    }
+}
+
+#[cfg(feature = "max3172x")]
+impl ResolutionSupport for ic::Max3172x {
+    fn get_resolution_mask(&self, _: Config) -> u16 {
+        BitMasks::RESOLUTION_16BIT
+    }
+
+    fn get_temperature_offset(&self, config: Config) -> i16 {
+        if config.is_extended_format() {
+            64
+        } else {
+            0
+        }
+    }
+
+    fn get_temperature_range(&self, _: Config) -> (i16, i16) {
+        (-55, 150)
+    }
+
+    fn get_conversion_time_ms(&self, _: Config) -> u32 {
+        Self::CONVERSION_TIME_MS
+    }
+
+    fn get_supply_current_ua(&self) -> (f32, f32) {
+        Self::SUPPLY_CURRENT_UA
+    }
+}
+
+#[cfg(feature = "lm75a")]
+impl ResolutionSupport for ic::Lm75a {
+    fn get_resolution_mask(&self, _: Config) -> u16 {
+        BitMasks::RESOLUTION_11BIT
+    }
+
+    fn get_limit_mask(&self, _: Config) -> u16 {
+        BitMasks::RESOLUTION_9BIT
+    }
+
+    fn get_conversion_time_ms(&self, _: Config) -> u32 {
+        Self::CONVERSION_TIME_MS
+    }
+
+    fn get_supply_current_ua(&self) -> (f32, f32) {
+        Self::SUPPLY_CURRENT_UA
+    }
+}
+
+#[cfg(feature = "g751")]
+impl ResolutionSupport for ic::G751 {
+    fn get_resolution_mask(&self, _: Config) -> u16 {
+        BitMasks::RESOLUTION_9BIT
+    }
+
+    fn get_conversion_time_ms(&self, _: Config) -> u32 {
+        Self::CONVERSION_TIME_MS
+    }
+
+    fn get_supply_current_ua(&self) -> (f32, f32) {
+        Self::SUPPLY_CURRENT_UA
+    }
+}
+
+#[cfg(feature = "mcp980x")]
+impl ResolutionSupport for ic::Mcp980x {
+    fn get_resolution_mask(&self, config: Config) -> u16 {
+        conversion::convert_resolution_to_mask(config.resolution())
+    }
+
+    fn get_limit_mask(&self, _: Config) -> u16 {
+        BitMasks::RESOLUTION_9BIT
+    }
+
+    fn get_conversion_time_ms(&self, config: Config) -> u32 {
+        Self::CONVERSION_TIME_MS[config.resolution() as usize]
+    }
+
+    fn get_supply_current_ua(&self) -> (f32, f32) {
+        Self::SUPPLY_CURRENT_UA
+    }
+}
+
+/// Evaluate an expression with `$ic` bound to the marker of the variant.
+macro_rules! dispatch {
+    ($variant:expr, $ic:ident => $body:expr) => {
+        match $variant {
+            Variant::Lm75 => {
+                let $ic = ic::Lm75;
+                $body
+            }
+            #[cfg(feature = "lm75a")]
+            Variant::Lm75a => {
+                let $ic = ic::Lm75a;
+                $body
+            }
+            #[cfg(feature = "pct2075")]
+            Variant::Pct2075 => {
+                let $ic = ic::Pct2075;
+                $body
+            }
+            #[cfg(feature = "g751")]
+            Variant::G751 => {
+                let $ic = ic::G751;
+                $body
+            }
+            #[cfg(feature = "mcp980x")]
+            Variant::Mcp980x => {
+                let $ic = ic::Mcp980x;
+                $body
+            }
+            #[cfg(feature = "ds7505")]
+            Variant::Ds7505 => {
+                let $ic = ic::Ds7505;
+                $body
+            }
+            #[cfg(feature = "at30ts75a")]
+            Variant::At30ts75a => {
+                let $ic = ic::At30ts75a;
+                $body
+            }
+            #[cfg(feature = "max750x")]
+            Variant::Max750x => {
+                let $ic = ic::Max750x;
+                $body
+            }
+            #[cfg(feature = "max6625")]
+            Variant::Max6625 => {
+                let $ic = ic::Max6625;
+                $body
+            }
+            #[cfg(feature = "max6626")]
+            Variant::Max6626 => {
+                let $ic = ic::Max6626;
+                $body
+            }
+            #[cfg(feature = "tcn75a")]
+            Variant::Tcn75a => {
+                let $ic = ic::Tcn75a;
+                $body
+            }
+            #[cfg(feature = "tmp75")]
+            Variant::Tmp75 => {
+                let $ic = ic::Tmp75;
+                $body
+            }
+            #[cfg(feature = "tmp1075")]
+            Variant::Tmp1075 => {
+                let $ic = ic::Tmp1075;
+                $body
+            }
+            #[cfg(feature = "stds75")]
+            Variant::Stds75 => {
+                let $ic = ic::Stds75;
+                $body
+            }
+            #[cfg(feature = "stlm75")]
+            Variant::Stlm75 => {
+                let $ic = ic::Stlm75;
+                $body
+            }
+            #[cfg(feature = "se95")]
+            Variant::Se95 => {
+                let $ic = ic::Se95;
+                $body
+            }
+            #[cfg(feature = "max3172x")]
+            Variant::Max3172x => {
+                let $ic = ic::Max3172x;
+                $body
+            }
+        }
+    };
+}
+
+impl ResolutionSupport for ic::Dynamic {
+    fn get_resolution_mask(&self, config: Config) -> u16 {
+        dispatch!(self.0, ic => ResolutionSupport::get_resolution_mask(&ic, config))
+    }
+
+    fn get_limit_mask(&self, config: Config) -> u16 {
+        dispatch!(self.0, ic => ResolutionSupport::get_limit_mask(&ic, config))
+    }
+
+    fn get_temperature_offset(&self, config: Config) -> i16 {
+        dispatch!(self.0, ic => ResolutionSupport::get_temperature_offset(&ic, config))
+    }
+
+    fn get_temperature_range(&self, config: Config) -> (i16, i16) {
+        dispatch!(self.0, ic => ResolutionSupport::get_temperature_range(&ic, config))
+    }
+
+    fn get_conversion_time_ms(&self, config: Config) -> u32 {
+        dispatch!(self.0, ic => ResolutionSupport::get_conversion_time_ms(&ic, config))
+    }
+
+    fn get_supply_current_ua(&self) -> (f32, f32) {
+        dispatch!(self.0, ic => ResolutionSupport::get_supply_current_ua(&ic))
+    }
+}
+
+/// Optional registers and features of a device.
+///
+/// This is used where the registers are accessed regardless of the device,
+/// like in `apply_settings()`, and to describe the device at runtime.
+pub trait RegisterSupport {
+    /// Whether the device features a sample rate (`TIdle`) register.
+    ///
+    /// This is `false` by default.
+    fn has_sample_rate_register(&self) -> bool {
+        false
+    }
+
+    /// Whether the device supports one-shot conversions.
+    ///
+    /// This is `false` by default.
+    fn has_one_shot(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(feature = "pct2075")]
+impl RegisterSupport for ic::Pct2075 {
+    fn has_sample_rate_register(&self) -> bool {
+        true
+    }
+}
+
+impl RegisterSupport for ic::Lm75 {}
+
+#[cfg(feature = "max750x")]
+impl RegisterSupport for ic::Max750x {}
+
+#[cfg(feature = "max6625")]
+impl RegisterSupport for ic::Max6625 {}
+
+#[cfg(feature = "max6626")]
+impl RegisterSupport for ic::Max6626 {}
+
+#[cfg(feature = "ds7505")]
+impl RegisterSupport for ic::Ds7505 {}
+
+#[cfg(feature = "at30ts75a")]
+impl RegisterSupport for ic::At30ts75a {}
+
+#[cfg(feature = "tcn75a")]
+impl RegisterSupport for ic::Tcn75a {
+    fn has_one_shot(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "tmp75")]
+impl RegisterSupport for ic::Tmp75 {
+    fn has_one_shot(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "tmp1075")]
+impl RegisterSupport for ic::Tmp1075 {
+    fn has_one_shot(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "stds75")]
+impl RegisterSupport for ic::Stds75 {}
+
+#[cfg(feature = "stlm75")]
+impl RegisterSupport for ic::Stlm75 {}
+
+#[cfg(feature = "se95")]
+impl RegisterSupport for ic::Se95 {}
+
+#[cfg(feature = "max3172x")]
+impl RegisterSupport for ic::Max3172x {
+    fn has_one_shot(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "lm75a")]
+impl RegisterSupport for ic::Lm75a {}
+
+#[cfg(feature = "g751")]
+impl RegisterSupport for ic::G751 {}
+
+#[cfg(feature = "mcp980x")]
+impl RegisterSupport for ic::Mcp980x {
+    fn has_one_shot(&self) -> bool {
+        true
+    }
+}
+
+/// Devices featuring a sample rate (`TIdle`) register.
+///
+/// `RegisterSupport::has_sample_rate_register()` must return `true` for these.
+pub trait HasSampleRate: RegisterSupport {}
+
+#[cfg(feature = "pct2075")]
+impl HasSampleRate for ic::Pct2075 {}
+
+impl ResolutionSupport for ic::Generic {
+    fn get_resolution_mask(&self, _: Config) -> u16 {
+        conversion::convert_resolution_to_mask(self.0)
+    }
+}
+
+impl RegisterSupport for ic::Generic {}
+
+impl RegisterSupport for ic::Dynamic {
+    fn has_sample_rate_register(&self) -> bool {
+        dispatch!(self.0, ic => ic.has_sample_rate_register())
+    }
+
+    fn has_one_shot(&self) -> bool {
+        dispatch!(self.0, ic => ic.has_one_shot())
+    }
+}
+
+/// Devices whose resolution can be configured.
+///
+/// The resolution is set through the `R0` and `R1` bits of the configuration register.
+pub trait HasResolutionConfig: ResolutionSupport {}
+
+#[cfg(feature = "ds7505")]
+impl HasResolutionConfig for ic::Ds7505 {}
+
+#[cfg(feature = "at30ts75a")]
+impl HasResolutionConfig for ic::At30ts75a {}
+
+#[cfg(feature = "mcp980x")]
+impl HasResolutionConfig for ic::Mcp980x {}
+
+#[cfg(feature = "tcn75a")]
+impl HasResolutionConfig for ic::Tcn75a {}
+
+#[cfg(feature = "tmp75")]
+impl HasResolutionConfig for ic::Tmp75 {}
+
+#[cfg(feature = "stds75")]
+impl HasResolutionConfig for ic::Stds75 {}
+
+/// Devices supporting one-shot conversions.
+///
+/// A conversion is started through the `OS` bit of the configuration register
+/// while the device is in shutdown.
+/// `RegisterSupport::has_one_shot()` must return `true` for these.
+pub trait HasOneShot: ResolutionSupport {}
+
+#[cfg(feature = "mcp980x")]
+impl HasOneShot for ic::Mcp980x {}
+
+#[cfg(feature = "tcn75a")]
+impl HasOneShot for ic::Tcn75a {}
+
+#[cfg(feature = "tmp75")]
+impl HasOneShot for ic::Tmp75 {}
+
+#[cfg(feature = "tmp1075")]
+impl HasOneShot for ic::Tmp1075 {}
+
+#[cfg(feature = "max3172x")]
+impl HasOneShot for ic::Max3172x {}
+
+/// Devices featuring a product ID register (`0x07`).
+pub trait HasProductId {}
+
+#[cfg(feature = "lm75a")]
+impl HasProductId for ic::Lm75a {}
@@ -0,0 +1,168 @@
+//! Software emulation of the OS output
+use crate::{FaultQueue, OsMode, OsPolarity, Settings};
+use embedded_hal::digital::OutputPin;
+
+/// Software emulation of the OS (alert) output on a GPIO
+///
+/// This is useful on boards where the OS output of the device is not
+/// connected. Every temperature reading passed to `update()` is evaluated
+/// against the OS and hysteresis temperatures in software and the pin is
+/// driven like the device would drive its OS output:
+///
+/// - In comparator mode, the output becomes active once the temperature
+///   exceeds the OS temperature and inactive once it falls below the
+///   hysteresis temperature.
+/// - In interrupt mode, the output becomes active on each of these events
+///   and stays active until `acknowledge()` is called.
+///
+/// In both modes, an event requires as many consecutive readings as set by
+/// the fault queue.
+///
+/// ```
+/// use embedded_hal::digital::{ErrorType, OutputPin};
+/// use lm75::{Settings, SoftwareOs};
+///
+/// # struct Led;
+/// # impl ErrorType for Led { type Error = core::convert::Infallible; }
+/// # impl OutputPin for Led {
+/// #     fn set_low(&mut self) -> Result<(), Self::Error> { Ok(()) }
+/// #     fn set_high(&mut self) -> Result<(), Self::Error> { Ok(()) }
+/// # }
+/// let settings = Settings {
+///     os_temperature: 60.0,
+///     hysteresis_temperature: 55.0,
+///     ..Settings::default()
+/// };
+/// let mut os = SoftwareOs::new(Led, &settings).unwrap();
+/// assert!(os.update(61.0).unwrap());
+/// assert!(os.update(58.0).unwrap());
+/// assert!(!os.update(54.5).unwrap());
+/// ```
+#[derive(Debug)]
+pub struct SoftwareOs<P> {
+    /// The pin emulating the OS output.
+    pin: P,
+    /// OS temperature (celsius).
+    os_temperature: f32,
+    /// Hysteresis temperature (celsius).
+    hysteresis_temperature: f32,
+    /// OS operation mode.
+    os_mode: OsMode,
+    /// OS polarity.
+    os_polarity: OsPolarity,
+    /// Number of consecutive faults necessary to trigger an event.
+    faults: u8,
+    /// Consecutive faults counted so far.
+    fault_count: u8,
+    /// Whether the temperature exceeded the OS temperature and did not fall
+    /// below the hysteresis temperature since.
+    over_temperature: bool,
+    /// Whether the output is active.
+    active: bool,
+    /// Whether the output was active since the latch was cleared.
+    latched: bool,
+}
+
+impl<P: OutputPin> SoftwareOs<P> {
+    /// Create a new instance with the OS temperatures, mode, polarity and
+    /// fault queue of the given settings.
+    ///
+    /// The pin is driven to the inactive level.
+    pub fn new(pin: P, settings: &Settings) -> Result<Self, P::Error> {
+        let mut os = SoftwareOs {
+            pin,
+            os_temperature: settings.os_temperature,
+            hysteresis_temperature: settings.hysteresis_temperature,
+            os_mode: settings.os_mode,
+            os_polarity: settings.os_polarity,
+            faults: match settings.fault_queue {
+                FaultQueue::_1 => 1,
+                FaultQueue::_2 => 2,
+                FaultQueue::_4 => 4,
+                FaultQueue::_6 => 6,
+            },
+            fault_count: 0,
+            over_temperature: false,
+            active: false,
+            latched: false,
+        };
+        os.drive(false)?;
+        Ok(os)
+    }
+
+    /// Evaluate a temperature reading (celsius) and drive the pin.
+    ///
+    /// Returns whether the output is active.
+    pub fn update(&mut self, temperature: f32) -> Result<bool, P::Error> {
+        let fault = if self.over_temperature {
+            temperature < self.hysteresis_temperature
+        } else {
+            temperature > self.os_temperature
+        };
+        if !fault {
+            self.fault_count = 0;
+            return Ok(self.active);
+        }
+        self.fault_count += 1;
+        if self.fault_count < self.faults {
+            return Ok(self.active);
+        }
+        self.fault_count = 0;
+        self.over_temperature = !self.over_temperature;
+        let active = match self.os_mode {
+            OsMode::Comparator => self.over_temperature,
+            OsMode::Interrupt => true,
+        };
+        self.drive(active)?;
+        Ok(active)
+    }
+
+    /// Reset the output in interrupt mode.
+    ///
+    /// This corresponds to reading a register of the device. In comparator
+    /// mode, the output is not modified.
+    pub fn acknowledge(&mut self) -> Result<(), P::Error> {
+        if self.os_mode == OsMode::Interrupt {
+            self.drive(false)?;
+        }
+        Ok(())
+    }
+
+    /// Whether the output is active.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Whether the output was active at any time since the latch was last
+    /// cleared.
+    ///
+    /// This allows a slow loop to notice alerts between its checks.
+    /// See `clear_latch()`.
+    pub fn alert_latched(&self) -> bool {
+        self.latched
+    }
+
+    /// Clear the alert latch. See `alert_latched()`.
+    pub fn clear_latch(&mut self) {
+        self.latched = false;
+    }
+
+    /// Destroy the instance, return the pin.
+    pub fn destroy(self) -> P {
+        self.pin
+    }
+
+    fn drive(&mut self, active: bool) -> Result<(), P::Error> {
+        match (self.os_polarity, active) {
+            (OsPolarity::ActiveLow, true) | (OsPolarity::ActiveHigh, false) => {
+                self.pin.set_low()?
+            }
+            (OsPolarity::ActiveLow, false) | (OsPolarity::ActiveHigh, true) => {
+                self.pin.set_high()?
+            }
+        }
+        self.active = active;
+        self.latched |= active;
+        Ok(())
+    }
+}
@@ -0,0 +1,315 @@
+//! Temperature types
+use crate::conversion;
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+#[cfg(feature = "uom")]
+use uom::si::{f32::ThermodynamicTemperature, thermodynamic_temperature::degree_celsius};
+
+/// Temperature in celsius
+///
+/// This keeps temperatures apart from other values like raw register
+/// contents. It can be compared, and temperature differences can be added
+/// and subtracted.
+///
+/// With the `uom` feature, it also converts from and into
+/// `uom::si::f32::ThermodynamicTemperature` so that these can be passed
+/// to the temperature setters as well.
+///
+/// ```
+/// use lm75::Celsius;
+///
+/// let os = Celsius(80.0);
+/// let hysteresis = os - Celsius(5.0);
+/// assert!(hysteresis < os);
+/// assert_eq!(167.0, hysteresis.to_fahrenheit());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Celsius(pub f32);
+
+impl Celsius {
+    /// Create a temperature from fahrenheit.
+    pub fn from_fahrenheit(temperature: f32) -> Self {
+        Celsius(conversion::convert_fahrenheit_to_celsius(temperature))
+    }
+
+    /// Create a temperature from kelvin.
+    pub fn from_kelvin(temperature: f32) -> Self {
+        Celsius(conversion::convert_kelvin_to_celsius(temperature))
+    }
+
+    /// Temperature in fahrenheit.
+    pub fn to_fahrenheit(self) -> f32 {
+        conversion::convert_celsius_to_fahrenheit(self.0)
+    }
+
+    /// Temperature in kelvin.
+    pub fn to_kelvin(self) -> f32 {
+        conversion::convert_celsius_to_kelvin(self.0)
+    }
+}
+
+impl From<f32> for Celsius {
+    fn from(temperature: f32) -> Self {
+        Celsius(temperature)
+    }
+}
+
+/// This allows passing floating-point literals where a `Celsius` value is
+/// expected.
+impl From<f64> for Celsius {
+    fn from(temperature: f64) -> Self {
+        Celsius(temperature as f32)
+    }
+}
+
+impl From<Celsius> for f32 {
+    fn from(temperature: Celsius) -> Self {
+        temperature.0
+    }
+}
+
+/// This requires the `uom` feature.
+#[cfg(feature = "uom")]
+impl From<ThermodynamicTemperature> for Celsius {
+    fn from(temperature: ThermodynamicTemperature) -> Self {
+        Celsius(temperature.get::<degree_celsius>())
+    }
+}
+
+/// This requires the `uom` feature.
+#[cfg(feature = "uom")]
+impl From<Celsius> for ThermodynamicTemperature {
+    fn from(temperature: Celsius) -> Self {
+        ThermodynamicTemperature::new::<degree_celsius>(temperature.0)
+    }
+}
+
+/// Temperature in millidegrees celsius
+///
+/// This allows passing integer temperatures to the setters without any
+/// floating-point math while avoiding plain integers being taken as degrees.
+///
+/// ```
+/// use lm75::{IntoTemperature, Millicelsius, Temperature};
+///
+/// assert_eq!(Temperature::Millicelsius(25_500), Millicelsius(25_500).into_temperature());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Millicelsius(pub i32);
+
+/// Temperature in one of the formats accepted by the temperature setters
+///
+/// Each format is converted to the register format with its own exact
+/// arithmetic, so e.g. millidegrees and raw values do not go through
+/// floating-point math.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Temperature {
+    /// Degrees celsius
+    Celsius(f32),
+    /// Millidegrees celsius
+    Millicelsius(i32),
+    /// Raw register value. See `RawTemperature`.
+    Raw(i16),
+}
+
+/// Conversion into a temperature accepted by the temperature setters
+///
+/// This is implemented for `f32` and `f64` (celsius), [`Celsius`],
+/// [`Millicelsius`], [`RawTemperature`] and, with the `uom` feature,
+/// `uom::si::f32::ThermodynamicTemperature`. Plain integers are not
+/// accepted to avoid confusing degrees with millidegrees or raw values.
+///
+/// ```
+/// use lm75::{Celsius, IntoTemperature, RawTemperature, Temperature};
+///
+/// assert_eq!(Temperature::Celsius(25.5), 25.5.into_temperature());
+/// assert_eq!(Temperature::Celsius(25.5), Celsius(25.5).into_temperature());
+/// assert_eq!(Temperature::Raw(0x1980), RawTemperature(0x1980).into_temperature());
+/// ```
+pub trait IntoTemperature {
+    /// Convert into a temperature.
+    fn into_temperature(self) -> Temperature;
+}
+
+impl IntoTemperature for Temperature {
+    fn into_temperature(self) -> Temperature {
+        self
+    }
+}
+
+impl IntoTemperature for f32 {
+    fn into_temperature(self) -> Temperature {
+        Temperature::Celsius(self)
+    }
+}
+
+impl IntoTemperature for f64 {
+    fn into_temperature(self) -> Temperature {
+        Temperature::Celsius(self as f32)
+    }
+}
+
+impl IntoTemperature for Celsius {
+    fn into_temperature(self) -> Temperature {
+        Temperature::Celsius(self.0)
+    }
+}
+
+impl IntoTemperature for Millicelsius {
+    fn into_temperature(self) -> Temperature {
+        Temperature::Millicelsius(self.0)
+    }
+}
+
+impl IntoTemperature for RawTemperature {
+    fn into_temperature(self) -> Temperature {
+        Temperature::Raw(self.0)
+    }
+}
+
+/// This requires the `uom` feature.
+#[cfg(feature = "uom")]
+impl IntoTemperature for ThermodynamicTemperature {
+    fn into_temperature(self) -> Temperature {
+        Temperature::Celsius(self.get::<degree_celsius>())
+    }
+}
+
+/// Raw temperature in the register format
+///
+/// The temperature registers of all supported devices hold the temperature
+/// in celsius as a two's complement Q8.8 fixed-point value, i.e. in units of
+/// 1/256ºC. This is also the format of `read_temperature_raw()` and
+/// `set_os_temperature_raw()`, so raw register frames, e.g. from DMA
+/// transfers or logs, can be decoded like the driver does.
+///
+/// The bits below the device resolution are not masked here. For the
+/// MAX31725/6 in the extended data format, the value is the temperature
+/// minus 64ºC.
+///
+/// ```
+/// use lm75::{Celsius, RawTemperature};
+///
+/// let raw = RawTemperature::from_register_bytes([0x19, 0x80]);
+/// assert_eq!(RawTemperature(0x1980), raw);
+/// assert_eq!(Celsius(25.5), raw.to_celsius());
+/// assert_eq!(25_500, raw.to_millicelsius());
+/// assert_eq!(Some(raw), RawTemperature::from_celsius(Celsius(25.5)));
+/// assert_eq!([0x19, 0x80], raw.to_register_bytes());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RawTemperature(pub i16);
+
+impl RawTemperature {
+    /// Number of raw units per degree celsius.
+    pub const UNITS_PER_DEGREE: i16 = 256;
+
+    /// Decode the two register bytes as sent by the device (MSB first).
+    pub const fn from_register_bytes(bytes: [u8; 2]) -> Self {
+        RawTemperature(i16::from_be_bytes(bytes))
+    }
+
+    /// Encode into the two register bytes as sent to the device (MSB first).
+    pub const fn to_register_bytes(self) -> [u8; 2] {
+        self.0.to_be_bytes()
+    }
+
+    /// Convert a temperature truncating towards zero to 1/256ºC.
+    ///
+    /// Returns `None` if the temperature is not finite or out of the
+    /// range of the format (-128ºC to 127.996ºC).
+    pub fn from_celsius(temperature: Celsius) -> Option<Self> {
+        conversion::convert_celsius_to_temp_raw(temperature.0).map(RawTemperature)
+    }
+
+    /// Temperature in celsius. This is exact.
+    pub fn to_celsius(self) -> Celsius {
+        Celsius(f32::from(self.0) / 256.0)
+    }
+
+    /// Convert a temperature in millidegrees celsius truncating towards
+    /// zero to 1/256ºC.
+    ///
+    /// Returns `None` if the temperature is out of the range of the format.
+    pub fn from_millicelsius(temperature: i32) -> Option<Self> {
+        let raw = conversion::convert_millicelsius_to_temp_raw(temperature, 0xFFFF);
+        i16::try_from(raw).ok().map(RawTemperature)
+    }
+
+    /// Temperature in millidegrees celsius truncated towards zero.
+    pub fn to_millicelsius(self) -> i32 {
+        conversion::convert_temp_raw_to_millicelsius(self.0)
+    }
+}
+
+impl From<i16> for RawTemperature {
+    fn from(raw: i16) -> Self {
+        RawTemperature(raw)
+    }
+}
+
+impl From<RawTemperature> for i16 {
+    fn from(raw: RawTemperature) -> Self {
+        raw.0
+    }
+}
+
+impl From<RawTemperature> for Celsius {
+    fn from(raw: RawTemperature) -> Self {
+        raw.to_celsius()
+    }
+}
+
+impl Add for Celsius {
+    type Output = Celsius;
+
+    fn add(self, other: Celsius) -> Celsius {
+        Celsius(self.0 + other.0)
+    }
+}
+
+impl Sub for Celsius {
+    type Output = Celsius;
+
+    fn sub(self, other: Celsius) -> Celsius {
+        Celsius(self.0 - other.0)
+    }
+}
+
+impl Neg for Celsius {
+    type Output = Celsius;
+
+    fn neg(self) -> Celsius {
+        Celsius(-self.0)
+    }
+}
+
+impl AddAssign for Celsius {
+    fn add_assign(&mut self, other: Celsius) {
+        self.0 += other.0;
+    }
+}
+
+impl SubAssign for Celsius {
+    fn sub_assign(&mut self, other: Celsius) {
+        self.0 -= other.0;
+    }
+}
+
+/// Print the temperature with one or two decimals, as needed to represent
+/// it exactly up to a resolution of 0.25ºC, unless a precision is given,
+/// e.g. `25.5°C` or `25.25°C`. Finer values are rounded to two decimals.
+impl core::fmt::Display for Celsius {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let decimals = f
+            .precision()
+            .unwrap_or_else(|| conversion::convert_temp_to_display_decimals(self.0));
+        write!(f, "{:.*}°C", decimals, self.0)
+    }
+}
@@ -0,0 +1,101 @@
+//! Software window alarm
+
+/// State of a `WindowAlarm`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WindowState {
+    /// The temperature is within the window (default)
+    #[default]
+    Normal,
+    /// The temperature fell below the low threshold
+    UnderTemperature,
+    /// The temperature rose above the high threshold
+    OverTemperature,
+}
+
+/// Software alarm with low and high temperature thresholds
+///
+/// The device only supports an over-temperature alarm. This evaluates the
+/// temperature readings passed to `update()` against a window in software
+/// and reports which side of it was violated, e.g. for cold-chain
+/// monitoring. An alarm is cleared once the temperature returns into the
+/// window by more than the hysteresis of the violated side.
+///
+/// ```
+/// use lm75::{WindowAlarm, WindowState};
+///
+/// let mut alarm = WindowAlarm::new(2.0, 8.0, 0.25, 0.5).unwrap();
+/// assert_eq!(WindowState::Normal, alarm.update(5.0));
+/// assert_eq!(WindowState::OverTemperature, alarm.update(8.5));
+/// assert_eq!(WindowState::OverTemperature, alarm.update(7.75));
+/// assert_eq!(WindowState::Normal, alarm.update(7.25));
+/// assert_eq!(WindowState::UnderTemperature, alarm.update(1.5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct WindowAlarm {
+    /// Low threshold (celsius).
+    low: f32,
+    /// High threshold (celsius).
+    high: f32,
+    /// Hysteresis of the low threshold (celsius).
+    low_hysteresis: f32,
+    /// Hysteresis of the high threshold (celsius).
+    high_hysteresis: f32,
+    /// Current state.
+    state: WindowState,
+}
+
+impl WindowAlarm {
+    /// Create a new alarm with the given thresholds and the hysteresis of
+    /// each of them (celsius).
+    ///
+    /// Returns `None` if any value is not finite, the low threshold is not
+    /// below the high threshold or a hysteresis is negative or does not fit
+    /// inside the window.
+    pub fn new(low: f32, high: f32, low_hysteresis: f32, high_hysteresis: f32) -> Option<Self> {
+        let window = high - low;
+        let fits = |hysteresis: f32| hysteresis >= 0.0 && hysteresis < window;
+        if !(low.is_finite()
+            && high.is_finite()
+            && low < high
+            && fits(low_hysteresis)
+            && fits(high_hysteresis))
+        {
+            return None;
+        }
+        Some(WindowAlarm {
+            low,
+            high,
+            low_hysteresis,
+            high_hysteresis,
+            state: WindowState::Normal,
+        })
+    }
+
+    /// Evaluate a temperature reading (celsius) and return the new state.
+    ///
+    /// NaN readings are ignored.
+    pub fn update(&mut self, temperature: f32) -> WindowState {
+        if temperature.is_nan() {
+            return self.state;
+        }
+        self.state = match self.state {
+            WindowState::OverTemperature if temperature >= self.high - self.high_hysteresis => {
+                WindowState::OverTemperature
+            }
+            WindowState::UnderTemperature if temperature <= self.low + self.low_hysteresis => {
+                WindowState::UnderTemperature
+            }
+            _ if temperature > self.high => WindowState::OverTemperature,
+            _ if temperature < self.low => WindowState::UnderTemperature,
+            _ => WindowState::Normal,
+        };
+        self.state
+    }
+
+    /// Current state.
+    pub fn state(&self) -> WindowState {
+        self.state
+    }
+}
@@ -0,0 +1,206 @@
+#![cfg(feature = "async")]
+
+use embedded_hal_mock::eh1::{
+    delay::{CheckedDelay, Transaction as DelayTrans},
+    digital::{Mock as PinMock, State as PinState, Transaction as PinTrans},
+    i2c::{Mock as I2cMock, Transaction as I2cTrans},
+};
+use futures::{executor::block_on, pin_mut, StreamExt};
+use lm75::{ic, Address, FaultQueue, Lm75Async, OsPolarity};
+
+const ADDR: u8 = 0b100_1000;
+const TEMPERATURE: u8 = 0x00;
+const CONFIGURATION: u8 = 0x01;
+const T_HYST: u8 = 0x02;
+const T_OS: u8 = 0x03;
+#[cfg(feature = "pct2075")]
+const T_IDLE: u8 = 0x04;
+
+fn new(transactions: &[I2cTrans]) -> Lm75Async<I2cMock, ic::Lm75> {
+    Lm75Async::new(I2cMock::new(transactions), Address::default())
+}
+
+#[cfg(feature = "pct2075")]
+fn new_pct2075(transactions: &[I2cTrans]) -> Lm75Async<I2cMock, ic::Pct2075> {
+    Lm75Async::new_pct2075(I2cMock::new(transactions), Address::default())
+}
+
+#[cfg(feature = "mcp980x")]
+fn new_mcp980x(transactions: &[I2cTrans]) -> Lm75Async<I2cMock, ic::Mcp980x> {
+    Lm75Async::new_mcp980x(I2cMock::new(transactions), Address::default())
+}
+
+fn destroy<IC>(sensor: Lm75Async<I2cMock, IC>) {
+    sensor.destroy().done();
+}
+
+#[test]
+fn can_read_temperature() {
+    let mut sensor = new(&[I2cTrans::write_read(
+        ADDR,
+        vec![TEMPERATURE],
+        vec![0b1110_0111, 0b1010_0101], // -24.5
+    )]);
+    assert_eq!(-24.5, block_on(sensor.read_temperature()).unwrap());
+    destroy(sensor);
+}
+
+#[test]
+fn can_enable_and_disable() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![CONFIGURATION, 1]),
+        I2cTrans::write(ADDR, vec![CONFIGURATION, 0]),
+    ]);
+    block_on(sensor.disable()).unwrap();
+    block_on(sensor.enable()).unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn can_set_fault_queue() {
+    let mut sensor = new(&[I2cTrans::write(ADDR, vec![CONFIGURATION, 0b0001_0000])]);
+    block_on(sensor.set_fault_queue(FaultQueue::_4)).unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn can_set_os_window() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![T_HYST, 0b0100_1011, 0]),
+        I2cTrans::write(ADDR, vec![T_OS, 0b0101_0000, 0]),
+    ]);
+    block_on(sensor.set_os_window(80.0, 75.0)).unwrap();
+    destroy(sensor);
+}
+
+#[test]
+#[cfg(feature = "pct2075")]
+fn can_set_and_read_sample_rate() {
+    let mut sensor = new_pct2075(&[
+        I2cTrans::write(ADDR, vec![T_IDLE, 0b0000_1111]),
+        I2cTrans::write_read(ADDR, vec![T_IDLE], vec![0b0000_1111]),
+    ]);
+    block_on(sensor.set_sample_rate(1500)).unwrap();
+    assert_eq!(1500, block_on(sensor.read_sample_rate()).unwrap());
+    destroy(sensor);
+}
+
+#[test]
+fn can_stream_temperature() {
+    let mut sensor = new(&[
+        I2cTrans::write_read(ADDR, vec![TEMPERATURE], vec![0b0001_1001, 0]),
+        I2cTrans::write_read(ADDR, vec![TEMPERATURE], vec![0b0001_1001, 0b1000_0000]),
+        I2cTrans::write_read(ADDR, vec![TEMPERATURE], vec![0b0001_1010, 0]),
+    ]);
+    let mut delay = CheckedDelay::new(&[
+        DelayTrans::async_delay_ms(500),
+        DelayTrans::async_delay_ms(500),
+    ]);
+    {
+        let readings = sensor.temperature_stream(&mut delay, 500);
+        pin_mut!(readings);
+        let readings: Vec<f32> = block_on(readings.take(3).map(Result::unwrap).collect());
+        assert_eq!(vec![25.0, 25.5, 26.0], readings);
+    }
+    delay.done();
+    destroy(sensor);
+}
+
+#[test]
+fn can_wait_for_alert_active_low() {
+    let sensor = new(&[]);
+    let mut pin = PinMock::new(&[PinTrans::wait_for_state(PinState::Low)]);
+    block_on(sensor.wait_for_alert(&mut pin)).unwrap();
+    pin.done();
+    destroy(sensor);
+}
+
+#[test]
+fn alert_monitor_follows_os_polarity() {
+    let sensor = new(&[I2cTrans::write(ADDR, vec![CONFIGURATION, 0b0000_0100])]);
+    let pin = PinMock::new(&[PinTrans::get(PinState::Low), PinTrans::get(PinState::High)]);
+    let mut monitor = sensor.with_os_pin(pin);
+    assert!(monitor.is_alert_active().unwrap());
+    block_on(monitor.sensor_mut().set_os_polarity(OsPolarity::ActiveHigh)).unwrap();
+    assert!(monitor.is_alert_active().unwrap());
+    let (sensor, mut pin) = monitor.destroy();
+    pin.done();
+    destroy(sensor);
+}
+
+#[test]
+fn can_wait_for_alert_active_high() {
+    let mut sensor = new(&[I2cTrans::write(ADDR, vec![CONFIGURATION, 0b0000_0100])]);
+    block_on(sensor.set_os_polarity(OsPolarity::ActiveHigh)).unwrap();
+    let mut pin = PinMock::new(&[PinTrans::wait_for_state(PinState::High)]);
+    block_on(sensor.wait_for_alert(&mut pin)).unwrap();
+    pin.done();
+    destroy(sensor);
+}
+
+#[test]
+#[cfg(feature = "mcp980x")]
+fn can_read_temperature_one_shot_mcp980x() {
+    let mut sensor = new_mcp980x(&[
+        I2cTrans::write(ADDR, vec![CONFIGURATION, 1]),
+        I2cTrans::write(ADDR, vec![CONFIGURATION, 0b1000_0001]),
+        I2cTrans::write_read(ADDR, vec![TEMPERATURE], vec![0b0001_1001, 0]),
+    ]);
+    let mut delay = CheckedDelay::new(&[DelayTrans::async_delay_ms(75)]);
+    let temp = block_on(sensor.read_temperature_one_shot(&mut delay)).unwrap();
+    assert_eq!(25.0, temp);
+    delay.done();
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_temperature_with_auto_shutdown() {
+    let sensor = new(&[
+        I2cTrans::write(ADDR, vec![CONFIGURATION, 0]),
+        I2cTrans::write_read(ADDR, vec![TEMPERATURE], vec![0b0001_1001, 0]),
+        I2cTrans::write(ADDR, vec![CONFIGURATION, 1]),
+    ]);
+    let delay = CheckedDelay::new(&[DelayTrans::async_delay_ms(300)]);
+    let mut sensor = sensor.with_auto_shutdown(delay);
+    assert_eq!(25.0, block_on(sensor.read_temperature()).unwrap());
+    let (sensor, mut delay) = sensor.destroy();
+    delay.done();
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_temperature_one_shot_lm75() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![CONFIGURATION, 0]),
+        I2cTrans::write_read(ADDR, vec![TEMPERATURE], vec![0b0001_1001, 0]),
+        I2cTrans::write(ADDR, vec![CONFIGURATION, 1]),
+    ]);
+    let mut delay = CheckedDelay::new(&[DelayTrans::async_delay_ms(300)]);
+    let temp = block_on(sensor.read_temperature_one_shot(&mut delay)).unwrap();
+    assert_eq!(25.0, temp);
+    delay.done();
+    destroy(sensor);
+}
+
+#[test]
+fn can_sample_with_duty_cycle() {
+    let sensor = new(&[
+        I2cTrans::write(ADDR, vec![CONFIGURATION, 0]),
+        I2cTrans::write_read(ADDR, vec![TEMPERATURE], vec![0b0001_1001, 0]),
+        I2cTrans::write(ADDR, vec![CONFIGURATION, 1]),
+        I2cTrans::write(ADDR, vec![CONFIGURATION, 0]),
+        I2cTrans::write_read(ADDR, vec![TEMPERATURE], vec![0b0001_1010, 0]),
+        I2cTrans::write(ADDR, vec![CONFIGURATION, 1]),
+    ]);
+    let delay = CheckedDelay::new(&[
+        DelayTrans::async_delay_ms(300),
+        DelayTrans::async_delay_ms(200),
+        DelayTrans::async_delay_ms(300),
+    ]);
+    let mut sampler = sensor.with_duty_cycle(delay, 500);
+    assert_eq!(25.0, block_on(sampler.sample()).unwrap());
+    assert_eq!(26.0, block_on(sampler.sample()).unwrap());
+    let (sensor, mut delay) = sampler.destroy();
+    delay.done();
+    destroy(sensor);
+}
@@ -1,5 +1,8 @@
 use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
-use lm75::{ic, Address, Error, Lm75};
+use lm75::{
+    ic::{self, HasOneShot, RegisterSupport, ResolutionSupport},
+    Address, Config, Error, Lm75, Resolution, Variant,
+};
 
 pub const ADDR: u8 = 0b100_1000;
 
@@ -10,6 +13,7 @@ impl Register {
     pub const CONFIGURATION: u8 = 0x01;
     pub const T_HYST: u8 = 0x02;
     pub const T_OS: u8 = 0x03;
+    #[cfg(feature = "pct2075")]
     pub const T_IDLE: u8 = 0x04;
 }
 
@@ -17,10 +21,168 @@ pub fn new(transactions: &[I2cTrans]) -> Lm75<I2cMock, ic::Lm75> {
     Lm75::new(I2cMock::new(transactions), Address::default())
 }
 
+#[cfg(feature = "pct2075")]
 pub fn new_pct2075(transactions: &[I2cTrans]) -> Lm75<I2cMock, ic::Pct2075> {
     Lm75::new_pct2075(I2cMock::new(transactions), Address::default())
 }
 
+pub fn new_dynamic(transactions: &[I2cTrans], variant: Variant) -> Lm75<I2cMock, ic::Dynamic> {
+    Lm75::new_dynamic(I2cMock::new(transactions), Address::default(), variant)
+}
+
+/// Device variant defined outside of the driver: 10-bit, 20ms conversions
+pub struct Custom;
+
+impl ResolutionSupport for Custom {
+    fn get_resolution_mask(&self, _: Config) -> u16 {
+        0b1111_1111_1100_0000
+    }
+
+    fn get_conversion_time_ms(&self, _: Config) -> u32 {
+        20
+    }
+}
+
+impl RegisterSupport for Custom {
+    fn has_one_shot(&self) -> bool {
+        true
+    }
+}
+
+impl HasOneShot for Custom {}
+
+pub fn new_custom(transactions: &[I2cTrans]) -> Lm75<I2cMock, Custom> {
+    Lm75::new_custom(I2cMock::new(transactions), Address::default(), Custom)
+}
+
+/// Custom device reporting no conversion time.
+pub struct CustomInstant;
+
+impl ResolutionSupport for CustomInstant {
+    fn get_resolution_mask(&self, _: Config) -> u16 {
+        0b1111_1111_1000_0000
+    }
+
+    fn get_conversion_time_ms(&self, _: Config) -> u32 {
+        0
+    }
+}
+
+impl RegisterSupport for CustomInstant {}
+
+/// Custom device with 2ºC steps in the OS and hysteresis registers.
+pub struct CustomCoarse;
+
+impl ResolutionSupport for CustomCoarse {
+    fn get_resolution_mask(&self, _: Config) -> u16 {
+        0b1111_1111_1000_0000
+    }
+
+    fn get_limit_mask(&self, _: Config) -> u16 {
+        0b1111_1110_0000_0000
+    }
+}
+
+impl RegisterSupport for CustomCoarse {}
+
+pub fn new_custom_coarse(transactions: &[I2cTrans]) -> Lm75<I2cMock, CustomCoarse> {
+    Lm75::new_custom(I2cMock::new(transactions), Address::default(), CustomCoarse)
+}
+
+pub fn new_custom_instant(transactions: &[I2cTrans]) -> Lm75<I2cMock, CustomInstant> {
+    Lm75::new_custom(
+        I2cMock::new(transactions),
+        Address::default(),
+        CustomInstant,
+    )
+}
+
+pub fn new_generic(
+    transactions: &[I2cTrans],
+    resolution: Resolution,
+) -> Lm75<I2cMock, ic::Generic> {
+    Lm75::new_generic(I2cMock::new(transactions), Address::default(), resolution)
+}
+
+#[cfg(feature = "mcp980x")]
+pub fn new_mcp980x(transactions: &[I2cTrans]) -> Lm75<I2cMock, ic::Mcp980x> {
+    Lm75::new_mcp980x(I2cMock::new(transactions), Address::default())
+}
+
+#[cfg(feature = "ds7505")]
+pub fn new_ds7505(transactions: &[I2cTrans]) -> Lm75<I2cMock, ic::Ds7505> {
+    Lm75::new_ds7505(I2cMock::new(transactions), Address::default())
+}
+
+#[cfg(feature = "at30ts75a")]
+pub fn new_at30ts75a(transactions: &[I2cTrans]) -> Lm75<I2cMock, ic::At30ts75a> {
+    Lm75::new_at30ts75a(I2cMock::new(transactions), Address::default())
+}
+
+#[cfg(feature = "max750x")]
+pub fn new_max750x(transactions: &[I2cTrans]) -> Lm75<I2cMock, ic::Max750x> {
+    Lm75::new_max750x(I2cMock::new(transactions), Address::default())
+}
+
+#[cfg(feature = "max6626")]
+pub fn new_max6626(transactions: &[I2cTrans]) -> Lm75<I2cMock, ic::Max6626> {
+    Lm75::new_max6626(I2cMock::new(transactions), Address::default())
+}
+
+#[cfg(feature = "tcn75a")]
+pub fn new_tcn75a(transactions: &[I2cTrans]) -> Lm75<I2cMock, ic::Tcn75a> {
+    Lm75::new_tcn75a(I2cMock::new(transactions), Address::default())
+}
+
+#[cfg(feature = "tmp75")]
+pub fn new_tmp75(transactions: &[I2cTrans]) -> Lm75<I2cMock, ic::Tmp75> {
+    Lm75::new_tmp75(I2cMock::new(transactions), Address::default())
+}
+
+#[cfg(feature = "tmp1075")]
+pub fn new_tmp1075(transactions: &[I2cTrans]) -> Lm75<I2cMock, ic::Tmp1075> {
+    Lm75::new_tmp1075(I2cMock::new(transactions), Address::default())
+}
+
+#[cfg(feature = "stds75")]
+pub fn new_stds75(transactions: &[I2cTrans]) -> Lm75<I2cMock, ic::Stds75> {
+    Lm75::new_stds75(I2cMock::new(transactions), Address::default())
+}
+
+#[cfg(feature = "se95")]
+pub fn new_se95(transactions: &[I2cTrans]) -> Lm75<I2cMock, ic::Se95> {
+    Lm75::new_se95(I2cMock::new(transactions), Address::default())
+}
+
+#[cfg(feature = "max3172x")]
+pub fn new_max3172x(transactions: &[I2cTrans]) -> Lm75<I2cMock, ic::Max3172x> {
+    Lm75::new_max3172x(I2cMock::new(transactions), Address::default())
+}
+
+#[cfg(feature = "lm75a")]
+pub fn new_lm75a(transactions: &[I2cTrans]) -> Lm75<I2cMock, ic::Lm75a> {
+    Lm75::new_lm75a(I2cMock::new(transactions), Address::default())
+}
+
+#[cfg(feature = "g751")]
+pub fn new_g751(transactions: &[I2cTrans]) -> Lm75<I2cMock, ic::G751> {
+    Lm75::new_g751(I2cMock::new(transactions), Address::default())
+}
+
+pub fn new_synced(transactions: &[I2cTrans]) -> Lm75<I2cMock, ic::Lm75> {
+    Lm75::new_synced(I2cMock::new(transactions), Address::default()).unwrap()
+}
+
+#[cfg(feature = "pct2075")]
+pub fn new_pct2075_synced(transactions: &[I2cTrans]) -> Lm75<I2cMock, ic::Pct2075> {
+    Lm75::new_pct2075_synced(I2cMock::new(transactions), Address::default()).unwrap()
+}
+
+#[cfg(feature = "mcp980x")]
+pub fn new_mcp980x_synced(transactions: &[I2cTrans]) -> Lm75<I2cMock, ic::Mcp980x> {
+    Lm75::new_mcp980x_synced(I2cMock::new(transactions), Address::default()).unwrap()
+}
+
 pub fn destroy<IC>(sensor: Lm75<I2cMock, IC>) {
     sensor.destroy().done();
 }
@@ -31,3 +193,10 @@ pub fn assert_invalid_input_data_error<T, E>(result: Result<T, Error<E>>) {
         _ => panic!("Did not return Error::InvalidInputData."),
     }
 }
+
+pub fn assert_out_of_range_error<T, E>(result: Result<T, Error<E>>) {
+    match result {
+        Err(Error::OutOfRange) => (),
+        _ => panic!("Did not return Error::OutOfRange."),
+    }
+}
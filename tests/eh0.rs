@@ -0,0 +1,81 @@
+#![cfg(feature = "eh0")]
+
+use embedded_hal_mock::eh0::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+use lm75::{
+    eh0::{I2cAdapter, I2cError, ReadOnlyI2cAdapter},
+    ic, Address, Error, Lm75,
+};
+
+const ADDR: u8 = 0b100_1000;
+const TEMPERATURE: u8 = 0x00;
+const CONFIGURATION: u8 = 0x01;
+const T_OS: u8 = 0x03;
+
+fn new(transactions: &[I2cTrans]) -> Lm75<I2cAdapter<I2cMock>, ic::Lm75> {
+    Lm75::new(
+        I2cAdapter::new(I2cMock::new(transactions)),
+        Address::default(),
+    )
+}
+
+fn destroy(sensor: Lm75<I2cAdapter<I2cMock>, ic::Lm75>) {
+    sensor.destroy().into_inner().done();
+}
+
+#[test]
+fn can_read_temperature() {
+    let mut sensor = new(&[I2cTrans::write_read(
+        ADDR,
+        vec![TEMPERATURE],
+        vec![0b1110_0111, 0b1010_0101], // -24.5
+    )]);
+    assert_eq!(-24.5, sensor.read_temperature().unwrap());
+    destroy(sensor);
+}
+
+#[test]
+fn can_disable() {
+    let mut sensor = new(&[I2cTrans::write(ADDR, vec![CONFIGURATION, 1])]);
+    sensor.disable().unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn can_set_os_temperature() {
+    let mut sensor = new(&[I2cTrans::write(ADDR, vec![T_OS, 0b0011_0010, 0b1000_0000])]);
+    sensor.set_os_temperature(50.5).unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_temperature_read_only() {
+    let mut sensor = Lm75::new(
+        ReadOnlyI2cAdapter::new(I2cMock::new(&[
+            I2cTrans::write_read(ADDR, vec![TEMPERATURE], vec![0b0001_1001, 0]),
+            I2cTrans::write_read(ADDR, vec![TEMPERATURE], vec![0b0001_1001, 0]),
+            I2cTrans::write_read(ADDR, vec![CONFIGURATION], vec![1]),
+        ])),
+        Address::default(),
+    );
+    assert_eq!(25.0, sensor.read_temperature().unwrap());
+    let measurement = sensor.read_measurement().unwrap();
+    assert_eq!(25.0, measurement.temperature);
+    assert!(measurement.shutdown);
+    sensor.destroy().into_inner().done();
+}
+
+#[test]
+fn cannot_configure_read_only() {
+    let mut sensor = Lm75::new(
+        ReadOnlyI2cAdapter::new(I2cMock::new(&[])),
+        Address::default(),
+    );
+    match sensor.disable() {
+        Err(Error::I2C {
+            source: I2cError::Unsupported,
+            ..
+        }) => (),
+        _ => panic!("Did not return I2cError::Unsupported."),
+    }
+    sensor.destroy().into_inner().done();
+}
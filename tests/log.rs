@@ -0,0 +1,49 @@
+#![cfg(feature = "log")]
+
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+use lm75::{Address, Lm75};
+use log::{Level, Log, Metadata, Record};
+use std::sync::Mutex;
+
+const ADDR: u8 = 0b100_1000;
+const TEMPERATURE: u8 = 0x00;
+const T_OS: u8 = 0x03;
+
+struct Logger(Mutex<Vec<String>>);
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= Level::Trace
+    }
+
+    fn log(&self, record: &Record) {
+        self.0.lock().unwrap().push(format!("{}", record.args()));
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: Logger = Logger(Mutex::new(Vec::new()));
+
+#[test]
+fn register_accesses_are_traced() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+    let mut sensor = Lm75::new(
+        I2cMock::new(&[
+            I2cTrans::write(ADDR, vec![T_OS, 0b0011_0010, 0]),
+            I2cTrans::write_read(ADDR, vec![TEMPERATURE], vec![0x19, 0x80]),
+        ]),
+        Address::default(),
+    );
+    sensor.set_os_temperature(50.0).unwrap();
+    sensor.read_temperature().unwrap();
+    sensor.destroy().done();
+    assert_eq!(
+        vec![
+            "write TOs to 0x48: [32, 00]".to_string(),
+            "read Temperature from 0x48: [19, 80]".to_string(),
+        ],
+        *LOGGER.0.lock().unwrap()
+    );
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "serde")]
+
+use lm75::{Config, DeviceState, FaultQueue, OsMode, OsPolarity, Settings};
+
+#[test]
+fn can_serialize_and_deserialize_settings() {
+    let settings = Settings {
+        shutdown: true,
+        fault_queue: FaultQueue::_4,
+        os_polarity: OsPolarity::ActiveHigh,
+        os_mode: OsMode::Interrupt,
+        os_temperature: 60.5,
+        hysteresis_temperature: 55.0,
+        sample_rate: Some(500),
+    };
+    let json = serde_json::to_string(&settings).unwrap();
+    assert_eq!(settings, serde_json::from_str(&json).unwrap());
+}
+
+#[test]
+fn can_serialize_and_deserialize_device_state() {
+    let state = DeviceState {
+        config: Config::default().with_os_mode(OsMode::Interrupt),
+        t_hyst: [0x4B, 0x00],
+        t_os: [0x50, 0x00],
+        t_idle: Some(0x0F),
+    };
+    let json = serde_json::to_string(&state).unwrap();
+    assert_eq!(state, serde_json::from_str(&json).unwrap());
+}
@@ -1,9 +1,56 @@
-use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
-use lm75::{FaultQueue, OsMode, OsPolarity};
+use embedded_hal::i2c::{Error as _, ErrorKind, NoAcknowledgeSource};
+use embedded_hal_mock::eh1::{
+    delay::{CheckedDelay, Transaction as DelayTrans},
+    digital::{Mock as PinMock, State as PinState, Transaction as PinTrans},
+    i2c::{Mock as I2cMock, Transaction as I2cTrans},
+};
+#[cfg(all(feature = "pct2075", feature = "max3172x"))]
+use lm75::Capabilities;
+#[cfg(feature = "tmp1075")]
+use lm75::ConversionRate;
+use lm75::{
+    ic, Access, Address, AddressSet, AlertConfig, AlertEvent, Celsius, ConfigUpdate, DeviceState,
+    Error, FaultQueue, GroupReading, Lm75, Lm75Group, Measurement, Millicelsius, OsMode,
+    OsPolarity, PowerMode, RawTemperature, Register as Reg, RegisterDump, Resolution, Rounding,
+    Settings, SoftwareOs, TemperatureSensor, Variant, WaitOutcome, WindowAlarm, WindowState,
+};
+#[cfg(feature = "pct2075")]
+use lm75::{Lm75Like, PowerProfile};
 
 mod common;
 
-use crate::common::{assert_invalid_input_data_error, destroy, new, new_pct2075, Register, ADDR};
+#[cfg(feature = "at30ts75a")]
+use crate::common::new_at30ts75a;
+#[cfg(feature = "ds7505")]
+use crate::common::new_ds7505;
+#[cfg(feature = "g751")]
+use crate::common::new_g751;
+#[cfg(feature = "lm75a")]
+use crate::common::new_lm75a;
+#[cfg(feature = "max3172x")]
+use crate::common::new_max3172x;
+#[cfg(feature = "max6626")]
+use crate::common::new_max6626;
+#[cfg(feature = "max750x")]
+use crate::common::new_max750x;
+#[cfg(feature = "se95")]
+use crate::common::new_se95;
+#[cfg(feature = "stds75")]
+use crate::common::new_stds75;
+#[cfg(feature = "tcn75a")]
+use crate::common::new_tcn75a;
+#[cfg(feature = "tmp1075")]
+use crate::common::new_tmp1075;
+#[cfg(feature = "tmp75")]
+use crate::common::new_tmp75;
+use crate::common::{
+    assert_invalid_input_data_error, assert_out_of_range_error, destroy, new, new_custom,
+    new_custom_coarse, new_custom_instant, new_dynamic, new_generic, new_synced, Register, ADDR,
+};
+#[cfg(feature = "mcp980x")]
+use crate::common::{new_mcp980x, new_mcp980x_synced};
+#[cfg(feature = "pct2075")]
+use crate::common::{new_pct2075, new_pct2075_synced};
 
 #[test]
 fn can_create_and_destroy_new() {
@@ -11,12 +58,65 @@ fn can_create_and_destroy_new() {
     destroy(sensor);
 }
 
+#[cfg(feature = "pct2075")]
 #[test]
 fn can_create_and_destroy_new_pct2075() {
     let sensor = new_pct2075(&[]);
     destroy(sensor);
 }
 
+#[test]
+fn can_create_synced_and_keep_config() {
+    let mut sensor = new_synced(&[
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0b0001_0110]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0001_0111]),
+    ]);
+    sensor.disable().unwrap();
+    destroy(sensor);
+}
+
+#[cfg(feature = "pct2075")]
+#[test]
+fn can_create_pct2075_synced_and_keep_config() {
+    let mut sensor = new_pct2075_synced(&[
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0b0000_0011]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0010]),
+    ]);
+    sensor.enable().unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn can_modify_config() {
+    let mut sensor = new(&[I2cTrans::write(
+        ADDR,
+        vec![Register::CONFIGURATION, 0b0001_0110],
+    )]);
+    sensor
+        .modify_config(|config| {
+            config
+                .with_fault_queue(FaultQueue::_4)
+                .with_os_polarity(OsPolarity::ActiveHigh)
+                .with_os_mode(OsMode::Interrupt)
+        })
+        .unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_modify_write_config() {
+    let mut sensor = new(&[
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0b0001_0110]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0001_0111]),
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0b0000_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0101]),
+    ])
+    .with_config_update(ConfigUpdate::ReadModifyWrite);
+    sensor.disable().unwrap();
+    sensor.set_os_polarity(OsPolarity::ActiveHigh).unwrap();
+    destroy(sensor);
+}
+
 #[test]
 fn can_enable() {
     let mut sensor = new(&[I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0])]);
@@ -25,38 +125,1939 @@ fn can_enable() {
 }
 
 #[test]
-fn can_disable() {
-    let mut sensor = new(&[I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 1])]);
+fn can_disable() {
+    let mut sensor = new(&[I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 1])]);
+    sensor.disable().unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_temperature() {
+    let mut sensor = new(&[I2cTrans::write_read(
+        ADDR,
+        vec![Register::TEMPERATURE],
+        vec![0b1110_0111, 0b1010_0101], // -24.5
+    )]);
+    let temp = sensor.read_temperature().unwrap();
+    assert!(-24.4 > temp);
+    assert!(-24.6 < temp);
+    destroy(sensor);
+}
+
+#[cfg(feature = "pct2075")]
+#[test]
+fn can_read_temperature_pct2075() {
+    let mut sensor = new_pct2075(&[I2cTrans::write_read(
+        ADDR,
+        vec![Register::TEMPERATURE],
+        vec![0b1110_0111, 0b1010_0101], // -24.375
+    )]);
+    let temp = sensor.read_temperature().unwrap();
+    assert!(-24.3 > temp);
+    assert!(-24.4 < temp);
+    destroy(sensor);
+}
+
+#[cfg(feature = "pct2075")]
+#[test]
+fn can_read_temperature_dynamic_pct2075() {
+    let mut sensor = new_dynamic(
+        &[I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b1110_0111, 0b1010_0101], // -24.375
+        )],
+        Variant::Pct2075,
+    );
+    assert_eq!(Variant::Pct2075, sensor.variant());
+    assert_eq!(0.125, sensor.resolution());
+    let temp = sensor.read_temperature().unwrap();
+    assert!(-24.3 > temp);
+    assert!(-24.4 < temp);
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_temperature_dynamic_lm75() {
+    let mut sensor = new_dynamic(
+        &[I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b1110_0111, 0b1010_0101], // -24.5
+        )],
+        Variant::Lm75,
+    );
+    assert_eq!(-24.5, sensor.read_temperature().unwrap());
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_temperature_generic() {
+    let mut sensor = new_generic(
+        &[
+            I2cTrans::write_read(
+                ADDR,
+                vec![Register::TEMPERATURE],
+                vec![0b0001_1001, 0b0101_1111],
+            ),
+            I2cTrans::write(ADDR, vec![Register::T_OS, 0b0001_1001, 0b0101_0000]),
+        ],
+        Resolution::Bits12,
+    );
+    assert_eq!(0.0625, sensor.resolution());
+    assert_eq!(25.3125, sensor.read_temperature().unwrap());
+    sensor.set_os_temperature(25.3125).unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn can_use_millicelsius() {
+    let mut sensor = new(&[
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b1110_0111, 0b1000_0000],
+        ),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0101_0000, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b0100_1010, 0b1000_0000]),
+    ]);
+    assert_eq!(-24_500, sensor.read_temperature_mc().unwrap());
+    assert_eq!(80_000, sensor.set_os_temperature_mc(80_000).unwrap());
+    assert_eq!(
+        74_500,
+        sensor.set_hysteresis_temperature_mc(74_900).unwrap()
+    );
+    assert_out_of_range_error(sensor.set_os_temperature_mc(125_500));
+    assert_out_of_range_error(sensor.set_os_temperature_mc(i32::MIN));
+    destroy(sensor);
+}
+
+#[cfg(feature = "tmp75")]
+#[test]
+#[cfg(feature = "fixed")]
+fn can_use_fixed_point_temperatures() {
+    use fixed::types::{I16F16, I8F8, I9F7};
+    let mut sensor = new_tmp75(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0110_0000]),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b1110_0111, 0b1111_0000],
+        ),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b0001_1001, 0b0001_0000],
+        ),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0101_0000, 0b0001_0000]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b1111_1111, 0b0000_0000]),
+    ]);
+    sensor.set_resolution(Resolution::Bits12).unwrap();
+    assert_eq!(
+        I16F16::from_num(-24.0625),
+        sensor.read_temperature_fixed::<I16F16>().unwrap()
+    );
+    assert_eq!(
+        I9F7::from_num(25.0625),
+        sensor.read_temperature_fixed::<I9F7>().unwrap()
+    );
+    assert_eq!(
+        I16F16::from_num(80.0625),
+        sensor
+            .set_os_temperature_fixed(I8F8::from_num(80.0625))
+            .unwrap()
+    );
+    assert_eq!(
+        I16F16::from_num(-1),
+        sensor.set_hysteresis_temperature_fixed(-1).unwrap()
+    );
+    assert_out_of_range_error(sensor.set_os_temperature_fixed(i64::MAX));
+    assert_out_of_range_error(sensor.set_os_temperature_fixed(I16F16::from_num(128)));
+    destroy(sensor);
+}
+
+#[cfg(feature = "max3172x")]
+#[test]
+#[cfg(feature = "fixed")]
+fn fixed_point_reading_out_of_range_max3172x() {
+    use fixed::types::{I16F16, I8F8};
+    let mut sensor = new_max3172x(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0010_0000]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0b0101_0110, 0]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0b0101_0110, 0]),
+    ]);
+    sensor.enable_extended_format().unwrap();
+    assert_eq!(
+        I16F16::from_num(150),
+        sensor.read_temperature_fixed::<I16F16>().unwrap()
+    );
+    assert_out_of_range_error(sensor.read_temperature_fixed::<I8F8>());
+    destroy(sensor);
+}
+
+#[test]
+#[cfg(feature = "uom")]
+fn can_use_uom_temperatures() {
+    use uom::si::{
+        f32::ThermodynamicTemperature,
+        thermodynamic_temperature::{degree_celsius, degree_fahrenheit},
+    };
+    let mut sensor = new(&[
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b0001_1001, 0b1000_0000],
+        ),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0101_0000, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b0100_1011, 0]),
+    ]);
+    let temperature = sensor.read_thermodynamic_temperature().unwrap();
+    assert_eq!(25.5, temperature.get::<degree_celsius>());
+    sensor
+        .set_os_temperature(ThermodynamicTemperature::new::<degree_celsius>(80.0))
+        .unwrap();
+    sensor
+        .set_hysteresis_temperature(ThermodynamicTemperature::new::<degree_fahrenheit>(167.0))
+        .unwrap();
+    destroy(sensor);
+}
+
+#[cfg(feature = "tmp75")]
+#[test]
+fn can_use_f64_temperatures() {
+    let mut sensor = new_tmp75(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0110_0000]),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b1110_0111, 0b1111_0000],
+        ),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0101_0000, 0b0001_0000]),
+    ]);
+    sensor.set_resolution(Resolution::Bits12).unwrap();
+    assert_eq!(-24.0625, sensor.read_temperature_f64().unwrap());
+    let os: f64 = 80.0625;
+    sensor.set_os_temperature(os).unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn can_use_fahrenheit_and_kelvin() {
+    let mut sensor = new(&[
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0b0001_1001, 0]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0b0001_1001, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0101_0000, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b0100_1011, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0101_0000, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b0100_1011, 0]),
+    ]);
+    assert_eq!(77.0, sensor.read_temperature_f().unwrap());
+    assert_eq!(298.15, sensor.read_temperature_k().unwrap());
+    assert_eq!(176.0, sensor.set_os_temperature_f(176.5).unwrap());
+    assert_eq!(167.0, sensor.set_hysteresis_temperature_f(167.0).unwrap());
+    assert_eq!(353.15, sensor.set_os_temperature_k(353.5).unwrap());
+    assert_eq!(348.15, sensor.set_hysteresis_temperature_k(348.15).unwrap());
+    assert!(matches!(
+        sensor.set_os_temperature_f(260.0),
+        Err(Error::OutOfRange)
+    ));
+    destroy(sensor);
+}
+
+#[test]
+fn can_convert_into_generic() {
+    let mut sensor = new(&[I2cTrans::write_read(
+        ADDR,
+        vec![Register::TEMPERATURE],
+        vec![0b0001_1001, 0b0111_1111],
+    )])
+    .into_generic(Resolution::Bits10);
+    assert_eq!(25.25, sensor.read_temperature().unwrap());
+    destroy(sensor);
+}
+
+#[test]
+fn can_try_read_temperature_in_steps() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::TEMPERATURE]),
+        I2cTrans::read(ADDR, vec![0b0001_1001, 0b1000_0000]), // 25.5
+        I2cTrans::read(ADDR, vec![0b0001_1010, 0]),           // 26
+    ]);
+    assert!(matches!(
+        sensor.try_read_temperature(),
+        Err(nb::Error::WouldBlock)
+    ));
+    assert_eq!(25.5, sensor.try_read_temperature().unwrap());
+    assert_eq!(26.0, sensor.try_read_temperature().unwrap());
+    destroy(sensor);
+}
+
+#[test]
+fn can_try_read_temperature_raw_after_blocking_read() {
+    let mut sensor = new(&[
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0b0001_1001, 0]),
+        I2cTrans::read(ADDR, vec![0b0001_1001, 0b1000_0000]),
+    ]);
+    sensor.read_temperature().unwrap();
+    assert_eq!(0x1980, sensor.try_read_temperature_raw().unwrap());
+    destroy(sensor);
+}
+
+#[test]
+fn try_read_temperature_sets_pointer_again_after_other_access() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::TEMPERATURE]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_1000]),
+        I2cTrans::write(ADDR, vec![Register::TEMPERATURE]),
+    ]);
+    assert!(matches!(
+        sensor.try_read_temperature(),
+        Err(nb::Error::WouldBlock)
+    ));
+    sensor.set_fault_queue(FaultQueue::_2).unwrap();
+    assert!(matches!(
+        sensor.try_read_temperature(),
+        Err(nb::Error::WouldBlock)
+    ));
+    destroy(sensor);
+}
+
+#[test]
+fn error_kind_forwards_i2c_error_kind() {
+    let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address);
+    let mut sensor = new(&[
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0, 0]).with_error(nack),
+    ]);
+    assert_eq!(nack, sensor.read_temperature().unwrap_err().kind());
+    assert_eq!(
+        ErrorKind::Other,
+        sensor.set_os_temperature(126.0).unwrap_err().kind()
+    );
+    destroy(sensor);
+}
+
+#[test]
+fn alert_follows_os_polarity() {
+    let sensor = new(&[I2cTrans::write(
+        ADDR,
+        vec![Register::CONFIGURATION, 0b0000_0100],
+    )]);
+    let pin = PinMock::new(&[
+        PinTrans::get(PinState::Low),
+        PinTrans::get(PinState::High),
+        PinTrans::get(PinState::High),
+    ]);
+    let mut monitor = sensor.with_os_pin(pin);
+    assert!(monitor.is_alert_active().unwrap());
+    assert!(!monitor.is_alert_active().unwrap());
+    monitor
+        .sensor_mut()
+        .set_os_polarity(OsPolarity::ActiveHigh)
+        .unwrap();
+    assert!(monitor.is_alert_active().unwrap());
+    let (sensor, mut pin) = monitor.destroy();
+    pin.done();
+    destroy(sensor);
+}
+
+#[test]
+fn can_wait_for_alert_on_pin() {
+    let pin = PinMock::new(&[
+        PinTrans::get(PinState::High),
+        PinTrans::get(PinState::High),
+        PinTrans::get(PinState::Low),
+        PinTrans::get(PinState::High),
+        PinTrans::get(PinState::High),
+    ]);
+    let mut monitor = new(&[]).with_os_pin(pin);
+    let mut delay = CheckedDelay::new(&[
+        DelayTrans::blocking_delay_ms(1),
+        DelayTrans::blocking_delay_ms(1),
+        DelayTrans::blocking_delay_ms(1),
+    ]);
+    assert_eq!(
+        WaitOutcome::Alert,
+        monitor.wait_for_alert(&mut delay, 5).unwrap()
+    );
+    assert_eq!(
+        WaitOutcome::Timeout,
+        monitor.wait_for_alert(&mut delay, 1).unwrap()
+    );
+    delay.done();
+    let (sensor, mut pin) = monitor.destroy();
+    pin.done();
+    destroy(sensor);
+}
+
+#[test]
+fn can_filter_os_pin_glitches() {
+    let pin = PinMock::new(&[
+        PinTrans::get(PinState::Low),
+        PinTrans::get(PinState::High),
+        PinTrans::get(PinState::Low),
+        PinTrans::get(PinState::Low),
+        PinTrans::get(PinState::High),
+        PinTrans::get(PinState::Low),
+        PinTrans::get(PinState::High),
+        PinTrans::get(PinState::High),
+    ]);
+    let mut monitor = new(&[]).with_os_pin(pin).with_debounce(2);
+    assert!(!monitor.is_alert_active().unwrap());
+    assert!(!monitor.is_alert_active().unwrap());
+    assert!(!monitor.is_alert_active().unwrap());
+    assert!(monitor.is_alert_active().unwrap());
+    assert!(monitor.is_alert_active().unwrap());
+    assert!(monitor.is_alert_active().unwrap());
+    assert!(monitor.is_alert_active().unwrap());
+    assert!(!monitor.is_alert_active().unwrap());
+    let (sensor, mut pin) = monitor.destroy();
+    pin.done();
+    destroy(sensor);
+}
+
+#[test]
+fn can_latch_alert_on_pin() {
+    let pin = PinMock::new(&[
+        PinTrans::get(PinState::High),
+        PinTrans::get(PinState::Low),
+        PinTrans::get(PinState::High),
+    ]);
+    let mut monitor = new(&[]).with_os_pin(pin);
+    assert!(!monitor.is_alert_active().unwrap());
+    assert!(!monitor.alert_latched());
+    assert!(monitor.is_alert_active().unwrap());
+    assert!(!monitor.is_alert_active().unwrap());
+    assert!(monitor.alert_latched());
+    monitor.clear_latch();
+    assert!(!monitor.alert_latched());
+    let (sensor, mut pin) = monitor.destroy();
+    pin.done();
+    destroy(sensor);
+}
+
+#[test]
+fn can_poll_alert_changes_in_comparator_mode() {
+    let pin = PinMock::new(&[
+        PinTrans::get(PinState::High),
+        PinTrans::get(PinState::Low),
+        PinTrans::get(PinState::Low),
+        PinTrans::get(PinState::High),
+    ]);
+    let mut monitor = new(&[]).with_os_pin(pin);
+    assert_eq!(None, monitor.poll().unwrap());
+    assert_eq!(Some(AlertEvent::OverTemperature), monitor.poll().unwrap());
+    assert_eq!(None, monitor.poll().unwrap());
+    assert_eq!(Some(AlertEvent::BelowHysteresis), monitor.poll().unwrap());
+    let (sensor, mut pin) = monitor.destroy();
+    pin.done();
+    destroy(sensor);
+}
+
+#[test]
+fn can_poll_alert_changes_in_interrupt_mode() {
+    let pin = PinMock::new(&[
+        PinTrans::get(PinState::Low),
+        PinTrans::get(PinState::High),
+        PinTrans::get(PinState::Low),
+        PinTrans::get(PinState::Low),
+    ]);
+    let mut monitor = new(&[I2cTrans::write(
+        ADDR,
+        vec![Register::CONFIGURATION, 0b0000_0010],
+    )])
+    .with_os_pin(pin);
+    monitor.sensor_mut().set_os_mode(OsMode::Interrupt).unwrap();
+    assert_eq!(Some(AlertEvent::OverTemperature), monitor.poll().unwrap());
+    assert_eq!(None, monitor.poll().unwrap());
+    assert_eq!(Some(AlertEvent::BelowHysteresis), monitor.poll().unwrap());
+    assert_eq!(None, monitor.poll().unwrap());
+    let (sensor, mut pin) = monitor.destroy();
+    pin.done();
+    destroy(sensor);
+}
+
+#[test]
+fn can_poll_for_alert() {
+    let mut sensor = new(&[
+        I2cTrans::write_read(ADDR, vec![Register::T_OS], vec![0x50, 0]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0x4F, 0x80]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0x50, 0]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0x50, 0x80]),
+        I2cTrans::write_read(ADDR, vec![Register::T_OS], vec![0x50, 0]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0x4F, 0]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0x4F, 0]),
+    ]);
+    let mut delay = CheckedDelay::new(&[
+        DelayTrans::blocking_delay_ms(300),
+        DelayTrans::blocking_delay_ms(300),
+        DelayTrans::blocking_delay_ms(100),
+    ]);
+    assert_eq!(
+        WaitOutcome::Alert,
+        sensor.poll_for_alert(&mut delay, 1000).unwrap()
+    );
+    assert_eq!(
+        WaitOutcome::Timeout,
+        sensor.poll_for_alert(&mut delay, 100).unwrap()
+    );
+    delay.done();
+    destroy(sensor);
+}
+
+#[test]
+fn poll_for_alert_times_out_without_conversion_time() {
+    let mut sensor = new_custom_instant(&[
+        I2cTrans::write_read(ADDR, vec![Register::T_OS], vec![0x50, 0]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0x4F, 0]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0x4F, 0]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0x4F, 0]),
+    ]);
+    let mut delay = CheckedDelay::new(&[
+        DelayTrans::blocking_delay_ms(1),
+        DelayTrans::blocking_delay_ms(1),
+    ]);
+    assert_eq!(
+        WaitOutcome::Timeout,
+        sensor.poll_for_alert(&mut delay, 2).unwrap()
+    );
+    delay.done();
+    destroy(sensor);
+}
+
+#[test]
+fn software_os_emulates_comparator_mode() {
+    let pin = PinMock::new(&[
+        PinTrans::set(PinState::High),
+        PinTrans::set(PinState::Low),
+        PinTrans::set(PinState::High),
+    ]);
+    let settings = Settings {
+        fault_queue: FaultQueue::_2,
+        ..Settings::default()
+    };
+    let mut os = SoftwareOs::new(pin, &settings).unwrap();
+    assert!(!os.update(81.0).unwrap());
+    assert!(!os.update(79.0).unwrap());
+    assert!(!os.update(81.0).unwrap());
+    assert!(os.update(82.0).unwrap());
+    assert!(os.update(74.0).unwrap());
+    assert!(os.update(76.0).unwrap());
+    assert!(os.update(74.0).unwrap());
+    assert!(!os.update(74.0).unwrap());
+    os.acknowledge().unwrap();
+    assert!(!os.is_active());
+    assert!(os.alert_latched());
+    os.clear_latch();
+    assert!(!os.alert_latched());
+    os.destroy().done();
+}
+
+#[test]
+fn software_os_emulates_interrupt_mode() {
+    let pin = PinMock::new(&[
+        PinTrans::set(PinState::Low),
+        PinTrans::set(PinState::High),
+        PinTrans::set(PinState::Low),
+        PinTrans::set(PinState::High),
+        PinTrans::set(PinState::Low),
+    ]);
+    let settings = Settings {
+        os_mode: OsMode::Interrupt,
+        os_polarity: OsPolarity::ActiveHigh,
+        ..Settings::default()
+    };
+    let mut os = SoftwareOs::new(pin, &settings).unwrap();
+    assert!(os.update(81.0).unwrap());
+    assert!(os.update(76.0).unwrap());
+    os.acknowledge().unwrap();
+    assert!(!os.update(90.0).unwrap());
+    assert!(os.update(74.0).unwrap());
+    os.acknowledge().unwrap();
+    assert!(!os.is_active());
+    os.destroy().done();
+}
+
+fn destroy_group(group: Lm75Group<I2cMock, ic::Lm75>) {
+    for sensor in group.destroy().into_iter().flatten() {
+        destroy(sensor);
+    }
+}
+
+#[test]
+fn can_read_sensor_group() {
+    let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address);
+    let mut group = Lm75Group::new();
+    assert!(group.is_empty());
+    let sensor = new(&[
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0x19, 0x80]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0x1A, 0]),
+    ]);
+    assert_eq!(0, group.add(sensor).unwrap());
+    let sensor = Lm75::new(
+        I2cMock::new(&[
+            I2cTrans::write_read(ADDR + 1, vec![Register::TEMPERATURE], vec![0, 0])
+                .with_error(nack),
+        ]),
+        Address::A1,
+    );
+    assert_eq!(1, group.add(sensor).unwrap());
+    assert_eq!(2, group.len());
+    assert_eq!(25.5, group.read_temperature(0).unwrap());
+    assert_invalid_input_data_error(group.read_temperature(2));
+    let results = group.read_temperatures();
+    assert!(matches!(results[0], Some(Ok(t)) if t == 26.0));
+    match &results[1] {
+        Some(Err(Error::I2C { source, .. })) => assert_eq!(nack, *source),
+        _ => panic!("Wrong result"),
+    }
+    assert!(results[2..].iter().all(Option::is_none));
+    destroy_group(group);
+}
+
+#[test]
+fn can_read_group_aggregate() {
+    let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address);
+    let mut group = Lm75Group::new();
+    let readings = [(0x19, 0), (0x1E, 0), (0, 0), (0x14, 0)];
+    for (index, (msb, lsb)) in readings.into_iter().enumerate() {
+        let address = ADDR + index as u8;
+        let mut transaction =
+            I2cTrans::write_read(address, vec![Register::TEMPERATURE], vec![msb, lsb]);
+        if index == 2 {
+            transaction = transaction.with_error(nack);
+        }
+        let sensor = Lm75::new(I2cMock::new(&[transaction]), address);
+        group.add(sensor).unwrap();
+    }
+    let GroupReading {
+        min,
+        max,
+        mean,
+        count,
+        errors,
+    } = group.read_all();
+    assert_eq!(Some(20.0), min);
+    assert_eq!(Some(30.0), max);
+    assert_eq!(Some(25.0), mean);
+    assert_eq!(3, count);
+    assert!(matches!(errors[2], Some(Error::I2C { source, .. }) if source == nack));
+    assert_eq!(1, errors.iter().flatten().count());
+    destroy_group(group);
+}
+
+#[test]
+fn group_aggregate_is_empty_without_readings() {
+    let mut group = Lm75Group::<I2cMock, ic::Lm75>::new();
+    let reading = group.read_all();
+    assert_eq!(
+        (None, None, None, 0),
+        (reading.min, reading.max, reading.mean, reading.count)
+    );
+    destroy_group(group);
+}
+
+#[test]
+fn cannot_add_duplicate_or_too_many_sensors_to_group() {
+    let mut group = Lm75Group::new();
+    for index in 0..Lm75Group::<I2cMock, ic::Lm75>::CAPACITY {
+        let sensor = Lm75::new(I2cMock::new(&[]), ADDR + index as u8);
+        assert_eq!(index, group.add(sensor).unwrap());
+    }
+    destroy(group.add(new(&[])).unwrap_err());
+    destroy(group.add(Lm75::new(I2cMock::new(&[]), 0x50)).unwrap_err());
+    destroy_group(group);
+}
+
+#[test]
+fn window_alarm_reports_violated_side() {
+    let mut alarm = WindowAlarm::new(2.0, 8.0, 0.5, 1.0).unwrap();
+    assert_eq!(WindowState::Normal, alarm.update(2.0));
+    assert_eq!(WindowState::UnderTemperature, alarm.update(1.75));
+    assert_eq!(WindowState::UnderTemperature, alarm.update(2.5));
+    assert_eq!(WindowState::Normal, alarm.update(2.75));
+    assert_eq!(WindowState::OverTemperature, alarm.update(8.25));
+    assert_eq!(WindowState::OverTemperature, alarm.update(7.25));
+    assert_eq!(WindowState::Normal, alarm.update(6.75));
+    assert_eq!(WindowState::OverTemperature, alarm.update(8.25));
+    assert_eq!(WindowState::OverTemperature, alarm.update(f32::NAN));
+    assert_eq!(WindowState::UnderTemperature, alarm.update(-5.0));
+    assert_eq!(WindowState::UnderTemperature, alarm.state());
+}
+
+#[test]
+fn cannot_create_invalid_window_alarm() {
+    let invalid = [
+        (8.0, 2.0, 0.5, 0.5),
+        (2.0, 2.0, 0.5, 0.5),
+        (2.0, 8.0, -0.5, 0.5),
+        (2.0, 8.0, 0.5, -0.5),
+        (2.0, 8.0, 6.0, 0.5),
+        (2.0, 8.0, 0.5, 7.0),
+        (f32::NAN, 8.0, 0.5, 0.5),
+        (2.0, f32::INFINITY, 0.5, 0.5),
+        (2.0, 8.0, f32::NAN, 0.5),
+        (2.0, 8.0, 0.5, f32::INFINITY),
+    ];
+    for (low, high, low_hysteresis, high_hysteresis) in invalid {
+        assert_eq!(
+            None,
+            WindowAlarm::new(low, high, low_hysteresis, high_hysteresis)
+        );
+    }
+}
+
+#[test]
+fn can_acknowledge_alert() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0010]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0x50, 0x80]),
+        I2cTrans::write_read(ADDR, vec![Register::T_HYST], vec![0x4B, 0]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0x4A, 0x80]),
+        I2cTrans::write_read(ADDR, vec![Register::T_HYST], vec![0x4B, 0]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0]),
+    ])
+    .into_interrupt_mode()
+    .unwrap();
+    assert_eq!(
+        AlertEvent::OverTemperature,
+        sensor.acknowledge_alert().unwrap()
+    );
+    assert_eq!(
+        AlertEvent::BelowHysteresis,
+        sensor.acknowledge_alert().unwrap()
+    );
+    destroy(sensor.into_comparator_mode().unwrap());
+}
+
+#[test]
+fn can_read_temperature_in_interrupt_mode() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0010]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0x50, 0x80]),
+    ])
+    .into_interrupt_mode()
+    .unwrap();
+    assert_eq!(80.5, sensor.read_temperature().unwrap());
+    destroy(sensor.destroy());
+}
+
+#[test]
+fn can_probe_device() {
+    let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address);
+    let mut sensor = new(&[
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0b0000_0110]),
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0]).with_error(nack),
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0])
+            .with_error(ErrorKind::Bus),
+    ]);
+    assert!(sensor.probe().unwrap());
+    assert!(!sensor.probe().unwrap());
+    assert_eq!(ErrorKind::Bus, sensor.probe().unwrap_err().kind());
+    destroy(sensor);
+}
+
+#[test]
+fn can_scan_bus() {
+    let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address);
+    let present = [Address::default(), Address::from(0x37)];
+    let transactions: Vec<_> = (0..0x80)
+        .filter(|&addr| Address::pct2075_range().any(|a| a == Address::from(addr)))
+        .map(|addr| {
+            let trans = I2cTrans::write_read(addr, vec![Register::CONFIGURATION], vec![0]);
+            if present.contains(&Address::from(addr)) {
+                trans
+            } else {
+                trans.with_error(nack)
+            }
+        })
+        .collect();
+    let mut i2c = I2cMock::new(&transactions);
+    let found = Lm75::scan(&mut i2c).unwrap();
+    let mut expected = AddressSet::default();
+    present.iter().for_each(|&address| expected.insert(address));
+    assert_eq!(expected, found);
+    assert!(found.iter().eq([Address::from(0x37), Address::default()]));
+    i2c.done();
+}
+
+#[test]
+fn can_get_and_set_address() {
+    let mut sensor = new(&[
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0x19, 0x80]),
+        I2cTrans::write_read(0x4F, vec![Register::TEMPERATURE], vec![0x1A, 0]),
+    ]);
+    assert_eq!(Address::default(), sensor.address());
+    assert_eq!(25.5, sensor.read_temperature().unwrap());
+    sensor.set_address((true, true, true));
+    assert_eq!(Address::from(0x4F), sensor.address());
+    assert_eq!(26.0, sensor.read_temperature().unwrap());
+    destroy(sensor);
+}
+
+#[test]
+fn i2c_errors_include_the_register_access() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0011_0010, 0]).with_error(ErrorKind::Bus),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0, 0])
+            .with_error(ErrorKind::Bus),
+    ]);
+    match sensor.set_os_temperature(50.0) {
+        Err(Error::I2C {
+            access: Access::Write(Reg::TOs),
+            source: ErrorKind::Bus,
+        }) => (),
+        _ => panic!("Did not return the write access."),
+    }
+    match sensor.read_temperature() {
+        Err(Error::I2C {
+            access: Access::Read(Reg::Temperature),
+            ..
+        }) => (),
+        _ => panic!("Did not return the read access."),
+    }
+    destroy(sensor);
+}
+
+#[test]
+fn can_display_errors() {
+    let error: Error<ErrorKind> = Error::I2C {
+        access: Access::Read(Reg::TOs),
+        source: ErrorKind::Bus,
+    };
+    assert_eq!("I²C bus error during Read(TOs): Bus", format!("{}", error));
+    let error: Error<ErrorKind> = Error::InvalidInputData;
+    assert_eq!("invalid input data", format!("{}", error));
+}
+
+#[cfg(feature = "core-error")]
+#[test]
+fn error_implements_core_error() {
+    fn assert_error<T: core::error::Error>(_: &T) {}
+    assert_error(&Error::<ErrorKind>::WriteVerificationFailed);
+}
+
+#[cfg(feature = "pct2075")]
+#[test]
+fn can_read_temperature_through_generic_sensor() {
+    fn read<S: TemperatureSensor>(sensor: &mut S) -> f32 {
+        sensor.read_temperature().unwrap_or_default()
+    }
+    let mut sensor = new_pct2075(&[I2cTrans::write_read(
+        ADDR,
+        vec![Register::TEMPERATURE],
+        vec![0b0001_1001, 0b0010_0000], // 25.125
+    )]);
+    assert_eq!(25.125, read(&mut sensor));
+    destroy(sensor);
+}
+
+#[cfg(feature = "pct2075")]
+#[test]
+fn can_use_mixed_sensors_as_trait_objects() {
+    let mut lm75 = new(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0000]),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b0001_1001, 0b0010_0000], // 25.0
+        ),
+    ]);
+    let mut pct2075 = new_pct2075(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0000]),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b0001_1001, 0b0010_0000], // 25.125
+        ),
+    ]);
+    {
+        let mut sensors: [&mut dyn Lm75Like<Error = Error<ErrorKind>>; 2] =
+            [&mut lm75, &mut pct2075];
+        let mut temperatures = [0.0; 2];
+        for (sensor, temperature) in sensors.iter_mut().zip(temperatures.iter_mut()) {
+            sensor.enable().unwrap();
+            *temperature = sensor.read_temperature().unwrap();
+        }
+        assert_eq!([25.0, 25.125], temperatures);
+        assert_eq!(0.5, sensors[0].resolution());
+        assert_eq!(0.125, sensors[1].resolution());
+    }
+    destroy(lm75);
+    destroy(pct2075);
+}
+
+#[cfg(all(feature = "pct2075", feature = "max3172x"))]
+#[test]
+fn can_describe_capabilities() {
+    let sensor = new(&[]);
+    assert_eq!(
+        Capabilities {
+            resolution_bits: 9,
+            has_sample_rate: false,
+            has_one_shot: false,
+            temperature_range: (-55, 125),
+        },
+        sensor.capabilities()
+    );
+    destroy(sensor);
+
+    let sensor = new_pct2075(&[]);
+    let capabilities = sensor.capabilities();
+    assert_eq!(11, capabilities.resolution_bits);
+    assert!(capabilities.has_sample_rate);
+    assert!(!capabilities.has_one_shot);
+    destroy(sensor);
+
+    let sensor = new_max3172x(&[]);
+    let capabilities = sensor.capabilities();
+    assert_eq!(16, capabilities.resolution_bits);
+    assert!(capabilities.has_one_shot);
+    assert_eq!((-55, 150), capabilities.temperature_range);
+    destroy(sensor);
+}
+
+#[cfg(feature = "tmp75")]
+#[test]
+fn can_describe_capabilities_at_resolution() {
+    let mut sensor = new_dynamic(
+        &[I2cTrans::write(
+            ADDR,
+            vec![Register::CONFIGURATION, 0b0110_0000],
+        )],
+        Variant::Tmp75,
+    );
+    assert_eq!(9, sensor.capabilities().resolution_bits);
+    sensor
+        .modify_config(|config| config.with_resolution(Resolution::Bits12))
+        .unwrap();
+    let capabilities = sensor.capabilities();
+    assert_eq!(12, capabilities.resolution_bits);
+    assert!(capabilities.has_one_shot);
+    destroy(sensor);
+}
+
+#[test]
+fn can_display_measurement() {
+    let measurement = Measurement {
+        temperature: 25.25,
+        shutdown: false,
+        resolution: 0.125,
+    };
+    assert_eq!("25.25°C", format!("{}", measurement));
+    assert_eq!("25.2°C", format!("{:.1}", measurement));
+    let measurement = Measurement {
+        temperature: -24.0,
+        shutdown: true,
+        resolution: 0.5,
+    };
+    assert_eq!("-24.0°C (shutdown)", format!("{}", measurement));
+    let measurement = Measurement {
+        temperature: 25.0,
+        shutdown: false,
+        resolution: 0.0625,
+    };
+    assert_eq!("25.00°C", format!("{}", measurement));
+}
+
+#[test]
+fn can_use_celsius() {
+    let mut temperature = Celsius(25.5);
+    assert_eq!("25.5°C", format!("{}", temperature));
+    assert_eq!("25.06°C", format!("{}", Celsius(25.0625)));
+    assert_eq!(Celsius(20.0), temperature - Celsius(5.5));
+    assert_eq!(Celsius(-25.5), -temperature);
+    temperature += Celsius(0.5);
+    assert!(temperature > Celsius(25.5));
+    assert_eq!(26.0, f32::from(temperature));
+    assert_eq!(Celsius(25.0), Celsius::from_fahrenheit(77.0));
+    assert_eq!(Celsius(25.0), Celsius::from_kelvin(298.15));
+    assert_eq!(298.15, Celsius(25.0).to_kelvin());
+}
+
+#[test]
+fn can_read_and_set_celsius() {
+    let mut sensor = new(&[
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0b0001_1001, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0101_0000, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b0100_1011, 0]),
+    ]);
+    let temperature = sensor.read_celsius().unwrap();
+    assert_eq!(Celsius(25.0), temperature);
+    let os = temperature + Celsius(55.0);
+    sensor.set_os_temperature(os).unwrap();
+    sensor
+        .set_hysteresis_temperature(os - Celsius(5.0))
+        .unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn implausible_temperature_is_sensor_fault() {
+    let mut sensor = new(&[
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0x7F, 0x80]), // 127.5
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0x80, 0]),    // -128
+    ])
+    .with_plausibility_check(true);
+    match sensor.read_temperature() {
+        Err(Error::SensorFault) => (),
+        _ => panic!("Did not return Error::SensorFault."),
+    }
+    match sensor.read_temperature_raw() {
+        Err(Error::SensorFault) => (),
+        _ => panic!("Did not return Error::SensorFault."),
+    }
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_temperature_beyond_operating_range() {
+    let mut sensor = new(&[
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0x7D, 0x80]), // 125.5
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0xC8, 0x80]), // -55.5
+    ]);
+    assert_eq!(125.5, sensor.read_temperature().unwrap());
+    assert_eq!(-55 * 256 - 128, sensor.read_temperature_raw().unwrap());
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_temperature_raw() {
+    let mut sensor = new(&[I2cTrans::write_read(
+        ADDR,
+        vec![Register::TEMPERATURE],
+        vec![0b1110_0111, 0b1010_0101], // -24.5
+    )]);
+    let temp = sensor.read_temperature_raw().unwrap();
+    assert_eq!(-24 * 256 - 128, temp);
+    destroy(sensor);
+}
+
+#[test]
+fn can_decode_raw_temperature() {
+    let data = [0b1110_0111, 0b1000_0000];
+    let mut sensor = new(&[I2cTrans::write_read(
+        ADDR,
+        vec![Register::TEMPERATURE],
+        data.to_vec(),
+    )]);
+    let raw = RawTemperature::from(sensor.read_temperature_raw().unwrap());
+    assert_eq!(RawTemperature::from_register_bytes(data), raw);
+    assert_eq!(Celsius(-24.5), raw.to_celsius());
+    assert_eq!(-24_500, raw.to_millicelsius());
+    assert_eq!(Some(raw), RawTemperature::from_millicelsius(-24_500));
+    assert_eq!(Some(raw), RawTemperature::from_celsius(Celsius(-24.5)));
+    assert_eq!(data, raw.to_register_bytes());
+    assert_eq!(None, RawTemperature::from_celsius(Celsius(f32::NAN)));
+    assert_eq!(None, RawTemperature::from_millicelsius(128_000));
+    destroy(sensor);
+}
+
+#[cfg(feature = "pct2075")]
+#[test]
+fn can_read_temperature_raw_pct2075() {
+    let mut sensor = new_pct2075(&[I2cTrans::write_read(
+        ADDR,
+        vec![Register::TEMPERATURE],
+        vec![0b1110_0111, 0b1010_0101], // -24.375
+    )]);
+    let temp = sensor.read_temperature_raw().unwrap();
+    assert_eq!(-24 * 256 - 96, temp);
+    destroy(sensor);
+}
+
+#[test]
+fn can_get_resolution() {
+    let sensor = new(&[]);
+    assert_eq!(0.5, sensor.resolution());
+    destroy(sensor);
+}
+
+#[cfg(feature = "lm75a")]
+#[test]
+fn can_read_temperature_lm75a() {
+    let mut sensor = new_lm75a(&[
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b1110_0111, 0b1010_0101], // -24.375
+        ),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0011_0010, 0b1000_0000]),
+    ]);
+    assert_eq!(0.125, sensor.resolution());
+    assert_eq!(-24.375, sensor.read_temperature().unwrap());
+    sensor.set_os_temperature(50.625).unwrap();
+    destroy(sensor);
+}
+
+#[cfg(feature = "g751")]
+#[test]
+fn can_create_and_use_g751() {
+    let mut sensor = new_g751(&[I2cTrans::write_read(
+        ADDR,
+        vec![Register::TEMPERATURE],
+        vec![0b1110_0111, 0b1010_0101], // -24.5
+    )]);
+    assert_eq!(0.5, sensor.resolution());
+    assert_eq!(-24.5, sensor.read_temperature().unwrap());
+    destroy(sensor);
+}
+
+#[cfg(feature = "pct2075")]
+#[test]
+fn can_get_resolution_pct2075() {
+    let sensor = new_pct2075(&[]);
+    assert_eq!(0.125, sensor.resolution());
+    destroy(sensor);
+}
+
+#[cfg(feature = "mcp980x")]
+#[test]
+fn can_set_resolution_mcp980x() {
+    let mut sensor = new_mcp980x(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0110_0000]),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b1110_0111, 0b1011_0000], // -24.3125
+        ),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0010_0001]),
+    ]);
+    assert_eq!(0.5, sensor.resolution());
+    sensor.set_resolution(Resolution::Bits12).unwrap();
+    assert_eq!(0.0625, sensor.resolution());
+    assert_eq!(-24.3125, sensor.read_temperature().unwrap());
+    sensor
+        .modify_config(|c| c.with_resolution(Resolution::Bits10).with_shutdown(true))
+        .unwrap();
+    assert_eq!(0.25, sensor.resolution());
+    destroy(sensor);
+}
+
+#[cfg(feature = "mcp980x")]
+#[test]
+fn can_create_mcp980x_synced_with_resolution() {
+    let mut sensor = new_mcp980x_synced(&[
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0b0100_0000]),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b0001_1001, 0b0110_0000],
+        ),
+    ]);
+    assert_eq!(0.125, sensor.resolution());
+    assert_eq!(25.375, sensor.read_temperature().unwrap());
+    destroy(sensor);
+}
+
+#[cfg(feature = "mcp980x")]
+#[test]
+fn mcp980x_limits_have_9bit_resolution() {
+    let mut sensor = new_mcp980x(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0110_0000]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0011_0010, 0b1000_0000]),
+    ]);
+    sensor.set_resolution(Resolution::Bits12).unwrap();
+    sensor.set_os_temperature(50.5625).unwrap();
+    destroy(sensor);
+}
+
+#[cfg(feature = "ds7505")]
+#[test]
+fn can_set_resolution_ds7505() {
+    let mut sensor = new_ds7505(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0100_0000]),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b0001_1001, 0b1111_0000], // 25.875 at 11-bit
+        ),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b0001_1001, 0b1000_0000]),
+    ]);
+    sensor.set_resolution(Resolution::Bits11).unwrap();
+    assert_eq!(0.125, sensor.resolution());
+    assert_eq!(25.875, sensor.read_temperature().unwrap());
+    sensor.set_hysteresis_temperature(25.875).unwrap();
+    destroy(sensor);
+}
+
+#[cfg(feature = "ds7505")]
+#[test]
+fn can_recall_nv_ds7505() {
+    let mut sensor = new_ds7505(&[
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0]),
+        I2cTrans::write(ADDR, vec![0x54]),
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0b0110_0010]),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b0001_1001, 0b1111_0000],
+        ),
+    ]);
+    sensor.recall_nv().unwrap();
+    assert_eq!(25.9375, sensor.read_temperature().unwrap());
+    destroy(sensor);
+}
+
+#[cfg(feature = "ds7505")]
+#[test]
+fn can_copy_to_nv_ds7505() {
+    let mut sensor = new_ds7505(&[
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0b0110_0000]),
+        I2cTrans::write(ADDR, vec![0x48]),
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0b1110_0000]),
+    ]);
+    sensor.copy_to_nv().unwrap();
+    assert!(sensor.is_nv_busy().unwrap());
+    destroy(sensor);
+}
+
+#[cfg(feature = "ds7505")]
+#[test]
+fn cannot_copy_to_nv_while_busy_ds7505() {
+    let mut sensor = new_ds7505(&[
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0b1000_0000]),
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0b1000_0000]),
+    ]);
+    assert!(matches!(sensor.copy_to_nv(), Err(Error::Busy)));
+    assert!(matches!(sensor.recall_nv(), Err(Error::Busy)));
+    destroy(sensor);
+}
+
+#[cfg(feature = "at30ts75a")]
+#[test]
+fn can_copy_nv_at30ts75a() {
+    let mut sensor = new_at30ts75a(&[
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0b0110_0000, 0]),
+        I2cTrans::write(ADDR, vec![0x48]),
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0b0110_0000, 1]),
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0b0110_0000, 0]),
+        I2cTrans::write(ADDR, vec![0xB8]),
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0b0010_0000]),
+    ]);
+    sensor.copy_to_nv().unwrap();
+    assert!(matches!(sensor.copy_from_nv(), Err(Error::Busy)));
+    sensor.copy_from_nv().unwrap();
+    assert_eq!(0.25, sensor.resolution());
+    destroy(sensor);
+}
+
+#[cfg(feature = "at30ts75a")]
+#[test]
+fn can_lock_nv_registers_at30ts75a() {
+    let mut sensor = new_at30ts75a(&[
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0, 0]),
+        I2cTrans::write_read(ADDR, vec![0x11], vec![0b0110_0000, 0]),
+        I2cTrans::write(ADDR, vec![0x11, 0b0110_0000, 0b0000_0010]),
+        I2cTrans::write_read(ADDR, vec![0x11], vec![0b0110_0000, 0b0000_0010]),
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0, 0]),
+        I2cTrans::write_read(ADDR, vec![0x11], vec![0b0110_0000, 0b0000_0010]),
+        I2cTrans::write(ADDR, vec![0x11, 0b0110_0000, 0]),
+        I2cTrans::write_read(ADDR, vec![0x11], vec![0b0110_0000, 0]),
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0, 0]),
+        I2cTrans::write_read(ADDR, vec![0x11], vec![0b0110_0000, 0]),
+        I2cTrans::write(ADDR, vec![0x11, 0b0110_0000, 0b0000_0100]),
+        I2cTrans::write_read(ADDR, vec![0x11], vec![0b0110_0000, 0b0000_0100]),
+        I2cTrans::write_read(ADDR, vec![Register::CONFIGURATION], vec![0, 1]),
+    ]);
+    sensor.lock_nv_registers().unwrap();
+    assert!(sensor.is_nv_locked().unwrap());
+    sensor.unlock_nv_registers().unwrap();
+    assert!(!sensor.is_nv_locked().unwrap());
+    sensor.lock_down_nv_registers().unwrap();
+    assert!(sensor.is_nv_locked().unwrap());
+    assert!(matches!(sensor.lock_nv_registers(), Err(Error::Busy)));
+    destroy(sensor);
+}
+
+#[cfg(feature = "max750x")]
+#[test]
+fn can_enable_and_disable_bus_timeout_max750x() {
+    let mut sensor = new_max750x(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b1000_0000]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b1000_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+    ]);
+    sensor.disable_bus_timeout().unwrap();
+    sensor.disable().unwrap();
+    sensor.enable_bus_timeout().unwrap();
+    destroy(sensor);
+}
+
+#[cfg(feature = "max6626")]
+#[test]
+fn can_read_temperature_max6626() {
+    let mut sensor = new_max6626(&[
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b1110_0111, 0b1011_1111], // -24.3125
+        ),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0011_0010, 0b1001_0000]),
+    ]);
+    assert_eq!(0.0625, sensor.resolution());
+    assert_eq!(-24.3125, sensor.read_temperature().unwrap());
+    sensor.set_os_temperature(50.5625).unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_temperature_one_shot_lm75() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0000]),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b0001_1001, 0b1000_0000],
+        ),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+    ]);
+    let mut delay = CheckedDelay::new(&[DelayTrans::blocking_delay_ms(300)]);
+    assert_eq!(25.5, sensor.read_temperature_one_shot(&mut delay).unwrap());
+    delay.done();
+    destroy(sensor);
+}
+
+#[test]
+fn shuts_down_after_failed_one_shot_reading_lm75() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0000]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0, 0])
+            .with_error(ErrorKind::Other),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+    ]);
+    let mut delay = CheckedDelay::new(&[DelayTrans::blocking_delay_ms(300)]);
+    assert!(sensor.read_temperature_one_shot(&mut delay).is_err());
+    delay.done();
+    destroy(sensor);
+}
+
+#[cfg(feature = "mcp980x")]
+#[test]
+fn can_read_temperature_one_shot_mcp980x() {
+    let mut sensor = new_mcp980x(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0010_0000]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0010_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b1010_0001]),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b0001_1001, 0b0100_0000],
+        ),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b1010_0001]),
+    ]);
+    let mut delay = CheckedDelay::new(&[DelayTrans::blocking_delay_ms(150)]);
+    sensor.set_resolution(Resolution::Bits10).unwrap();
+    let temp = sensor.read_temperature_one_shot(&mut delay).unwrap();
+    assert_eq!(25.25, temp);
+    // already in shutdown
+    sensor.trigger_one_shot().unwrap();
+    delay.done();
+    destroy(sensor);
+}
+
+#[test]
+fn can_wait_for_conversion() {
+    let sensor = new(&[]);
+    let mut delay = CheckedDelay::new(&[DelayTrans::blocking_delay_ms(300)]);
+    sensor.wait_for_conversion(&mut delay);
+    delay.done();
+    destroy(sensor);
+}
+
+#[cfg(feature = "ds7505")]
+#[test]
+fn can_wait_for_conversion_at_resolution() {
+    let mut sensor = new_ds7505(&[I2cTrans::write(
+        ADDR,
+        vec![Register::CONFIGURATION, 0b0100_0000],
+    )]);
+    let mut delay = CheckedDelay::new(&[
+        DelayTrans::blocking_delay_ms(25),
+        DelayTrans::blocking_delay_ms(100),
+    ]);
+    sensor.wait_for_conversion(&mut delay);
+    sensor.set_resolution(Resolution::Bits11).unwrap();
+    sensor.wait_for_conversion(&mut delay);
+    delay.done();
+    destroy(sensor);
+}
+
+#[cfg(all(feature = "mcp980x", feature = "tmp1075"))]
+#[test]
+fn can_get_conversion_times() {
+    assert_eq!(300, ic::Lm75::CONVERSION_TIME_MS);
+    assert_eq!(10, ic::Tmp1075::CONVERSION_TIME_MS);
+    assert_eq!([75, 150, 300, 600], ic::Mcp980x::CONVERSION_TIME_MS);
+}
+
+#[cfg(all(feature = "mcp980x", feature = "tmp75"))]
+#[test]
+fn can_estimate_supply_current() {
+    let mut sensor = new(&[]);
+    assert_eq!(250.0, sensor.estimate_supply_current_ua(None).unwrap());
+    assert_eq!(
+        63.25,
+        sensor.estimate_supply_current_ua(Some(1200)).unwrap()
+    );
+    destroy(sensor);
+
+    let mut sensor = new_mcp980x(&[]);
+    assert_eq!(50.75, sensor.estimate_supply_current_ua(Some(300)).unwrap());
+    assert_eq!(200.0, sensor.estimate_supply_current_ua(Some(50)).unwrap());
+    destroy(sensor);
+
+    let mut sensor = new_dynamic(&[], Variant::Tmp75);
+    assert_eq!(50.0, sensor.estimate_supply_current_ua(None).unwrap());
+    assert_eq!((50.0, 0.1), ic::Tmp75::SUPPLY_CURRENT_UA);
+    destroy(sensor);
+}
+
+#[cfg(feature = "pct2075")]
+#[test]
+fn supply_current_estimate_accounts_for_pct2075_sample_rate() {
+    let mut sensor = new_pct2075(&[
+        I2cTrans::write_read(ADDR, vec![Register::T_IDLE], vec![1]),
+        I2cTrans::write_read(ADDR, vec![Register::T_IDLE], vec![2]),
+        I2cTrans::write_read(ADDR, vec![Register::T_IDLE], vec![2]),
+    ]);
+    assert_eq!(75.0, sensor.estimate_supply_current_ua(None).unwrap());
+    // 0.1 + 74.9 * 128 / 228
+    let continuous = sensor.estimate_supply_current_ua(None).unwrap();
+    assert!((continuous - 42.149_12).abs() < 1e-3);
+    // awake for 228ms like with_duty_cycle(), then in shutdown
+    let sampled = sensor.estimate_supply_current_ua(Some(1140)).unwrap();
+    assert!((sampled - (0.2 * continuous + 0.8 * 0.1)).abs() < 1e-3);
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_temperature_custom_variant() {
+    let mut sensor = new_custom(&[I2cTrans::write_read(
+        ADDR,
+        vec![Register::TEMPERATURE],
+        vec![0b0001_1001, 0b0111_1111],
+    )]);
+    assert_eq!(0.25, sensor.resolution());
+    assert_eq!(25.25, sensor.read_temperature().unwrap());
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_temperature_one_shot_custom_variant() {
+    let mut sensor = new_custom(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b1000_0001]),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b0001_1001, 0b0100_0000],
+        ),
+    ]);
+    let mut delay = CheckedDelay::new(&[DelayTrans::blocking_delay_ms(20)]);
+    assert_eq!(25.25, sensor.read_temperature_one_shot(&mut delay).unwrap());
+    delay.done();
+    destroy(sensor);
+}
+
+#[cfg(feature = "tcn75a")]
+#[test]
+fn can_read_temperature_one_shot_tcn75a() {
+    let mut sensor = new_tcn75a(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0110_0000]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0110_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b1110_0001]),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b0001_1001, 0b0001_0000],
+        ),
+    ]);
+    let mut delay = CheckedDelay::new(&[DelayTrans::blocking_delay_ms(600)]);
+    sensor.set_resolution(Resolution::Bits12).unwrap();
+    let temp = sensor.read_temperature_one_shot(&mut delay).unwrap();
+    assert_eq!(25.0625, temp);
+    delay.done();
+    destroy(sensor);
+}
+
+#[cfg(feature = "tmp75")]
+#[test]
+fn can_use_12bit_limits_tmp75() {
+    let mut sensor = new_tmp75(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0010_0000]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b0001_1001, 0b0001_0000]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0010_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b1010_0001]),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b0001_1001, 0b0111_0000],
+        ),
+    ]);
+    let mut delay = CheckedDelay::new(&[DelayTrans::blocking_delay_ms(75)]);
+    sensor.set_resolution(Resolution::Bits10).unwrap();
+    sensor.set_hysteresis_temperature(25.0625).unwrap();
+    let temp = sensor.read_temperature_one_shot(&mut delay).unwrap();
+    assert_eq!(25.25, temp);
+    delay.done();
+    destroy(sensor);
+}
+
+#[cfg(feature = "tmp1075")]
+#[test]
+fn can_read_device_id_tmp1075() {
+    let mut sensor = new_tmp1075(&[I2cTrans::write_read(ADDR, vec![0x0F], vec![0x75, 0x00])]);
+    assert_eq!(0x7500, sensor.read_device_id().unwrap());
+    destroy(sensor);
+}
+
+#[cfg(feature = "tmp1075")]
+#[test]
+fn can_set_conversion_rate_tmp1075() {
+    let mut sensor = new_tmp1075(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0100_0000]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0010_0000]),
+    ]);
+    sensor.set_conversion_rate(ConversionRate::Ms110).unwrap();
+    sensor.set_conversion_rate(ConversionRate::Ms55).unwrap();
+    destroy(sensor);
+}
+
+#[cfg(feature = "tmp1075")]
+#[test]
+fn can_read_temperature_one_shot_tmp1075() {
+    let mut sensor = new_tmp1075(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b1000_0001]),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b1110_0111, 0b1011_0000],
+        ),
+    ]);
+    let mut delay = CheckedDelay::new(&[DelayTrans::blocking_delay_ms(10)]);
+    let temp = sensor.read_temperature_one_shot(&mut delay).unwrap();
+    assert_eq!(-24.3125, temp);
+    delay.done();
+    destroy(sensor);
+}
+
+#[cfg(feature = "stds75")]
+#[test]
+fn can_set_resolution_stds75() {
+    let mut sensor = new_stds75(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0110_0000]),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b0001_1001, 0b0001_0000],
+        ),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0001_1001, 0]),
+    ]);
+    sensor.set_resolution(Resolution::Bits12).unwrap();
+    assert_eq!(25.0625, sensor.read_temperature().unwrap());
+    sensor.set_os_temperature(25.0625).unwrap();
+    destroy(sensor);
+}
+
+#[cfg(feature = "se95")]
+#[test]
+fn can_read_temperature_se95() {
+    let mut sensor = new_se95(&[
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b1110_0111, 0b1010_1111],
+        ),
+        I2cTrans::write_read(ADDR, vec![0x07], vec![0xA1]),
+    ]);
+    assert_eq!(0.03125, sensor.resolution());
+    assert_eq!(-24.34375, sensor.read_temperature().unwrap());
+    assert_eq!(0xA1, sensor.read_manufacturer_id().unwrap());
+    destroy(sensor);
+}
+
+#[cfg(feature = "max3172x")]
+#[test]
+fn can_use_16bit_resolution_max3172x() {
+    let mut sensor = new_max3172x(&[
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b0001_1001, 0b0000_0001],
+        ),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0001_1001, 0b0000_0001]),
+    ]);
+    assert_eq!(1.0 / 256.0, sensor.resolution());
+    assert_eq!(25.0 + 1.0 / 256.0, sensor.read_temperature().unwrap());
+    sensor.set_os_temperature(25.0 + 1.0 / 256.0).unwrap();
+    destroy(sensor);
+}
+
+#[cfg(feature = "max3172x")]
+#[test]
+fn can_use_extended_format_max3172x() {
+    let mut sensor = new_max3172x(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0010_0000]),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b1101_1001, 0b1000_0000],
+        ),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b1101_1001, 0b1000_0000],
+        ),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0001_0000, 0]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0]),
+    ]);
+    sensor.enable_extended_format().unwrap();
+    assert_eq!(25.5, sensor.read_temperature().unwrap());
+    assert_eq!(-38 * 256 - 128, sensor.read_temperature_raw().unwrap());
+    sensor.set_os_temperature(80.0).unwrap();
+    sensor.disable_extended_format().unwrap();
+    destroy(sensor);
+}
+
+#[cfg(feature = "max3172x")]
+#[test]
+fn can_use_extended_temperature_range_max3172x() {
+    let mut sensor = new_max3172x(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0010_0000]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0b0101_0110, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0100_1100, 0]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0]),
+    ]);
+    sensor.enable_extended_format().unwrap();
+    assert_eq!(150.0, sensor.read_temperature().unwrap());
+    sensor.set_os_temperature(140.0).unwrap();
+    assert_out_of_range_error(sensor.set_os_temperature(150.5));
+    sensor.disable_extended_format().unwrap();
+    // not representable in the normal data format
+    assert_out_of_range_error(sensor.set_os_temperature(140.0));
+    destroy(sensor);
+}
+
+#[cfg(feature = "max3172x")]
+#[test]
+fn can_use_millicelsius_in_extended_format_max3172x() {
+    let mut sensor = new_max3172x(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0010_0000]),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b1101_1001, 0b1000_0000],
+        ),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0001_0000, 0]),
+    ]);
+    sensor.enable_extended_format().unwrap();
+    assert_eq!(25_500, sensor.read_temperature_mc().unwrap());
+    assert_eq!(80_000, sensor.set_os_temperature_mc(80_000).unwrap());
+    assert_out_of_range_error(sensor.set_os_temperature_mc(150_500));
+    destroy(sensor);
+}
+
+#[cfg(feature = "tmp75")]
+#[test]
+fn can_use_extended_temperature_range_tmp75() {
+    let mut sensor = new_tmp75(&[I2cTrans::write(ADDR, vec![Register::T_OS, 0b0111_1110, 0])]);
+    sensor.set_os_temperature(126.0).unwrap();
+    assert_out_of_range_error(sensor.set_os_temperature(127.5));
+    destroy(sensor);
+}
+
+#[test]
+fn cannot_set_temperature_above_range_lm75() {
+    let mut sensor = new(&[]);
+    assert_out_of_range_error(sensor.set_os_temperature(126.0));
+    destroy(sensor);
+}
+
+#[cfg(feature = "lm75a")]
+#[test]
+fn can_verify_identity() {
+    let mut sensor = new_lm75a(&[
+        I2cTrans::write_read(ADDR, vec![0x07], vec![0xA1]),
+        I2cTrans::write_read(ADDR, vec![0x07], vec![0xA1]),
+        I2cTrans::write_read(ADDR, vec![0x07], vec![0x00]),
+    ]);
+    assert_eq!(0xA1, sensor.read_product_id().unwrap());
+    sensor.verify_identity(0xA1).unwrap();
+    assert!(matches!(
+        sensor.verify_identity(0xA1),
+        Err(Error::WrongDevice)
+    ));
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_temperature_with_auto_shutdown() {
+    fn read<S: TemperatureSensor>(sensor: &mut S) -> Result<f32, S::Error> {
+        sensor.read_temperature()
+    }
+    let sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0000]),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b0001_1001, 0b1000_0000],
+        ),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0011]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0010]),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b0001_1010, 0b0000_0000],
+        ),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0011]),
+    ]);
+    let delay = CheckedDelay::new(&[
+        DelayTrans::blocking_delay_ms(300),
+        DelayTrans::blocking_delay_ms(300),
+    ]);
+    let mut sensor = sensor.with_auto_shutdown(delay);
+    assert_eq!(25.5, sensor.read_temperature().unwrap());
+    sensor.sensor_mut().set_os_mode(OsMode::Interrupt).unwrap();
+    assert_eq!(26.0, read(&mut sensor).unwrap());
+    let (sensor, mut delay) = sensor.destroy();
+    delay.done();
+    destroy(sensor);
+}
+
+#[test]
+fn can_sample_with_duty_cycle() {
+    let sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0000]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0b0001_1001, 0]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0000]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0b0001_1010, 0]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+    ]);
+    let delay = CheckedDelay::new(&[
+        DelayTrans::blocking_delay_ms(300),
+        DelayTrans::blocking_delay_ms(700),
+        DelayTrans::blocking_delay_ms(300),
+    ]);
+    let mut sampler = sensor.with_duty_cycle(delay, 1000);
+    assert_eq!(25.0, sampler.sample().unwrap());
+    assert_eq!(26.0, sampler.sample().unwrap());
+    let (sensor, mut delay) = sampler.destroy();
+    delay.done();
+    destroy(sensor);
+}
+
+#[cfg(feature = "pct2075")]
+#[test]
+fn duty_cycle_accounts_for_pct2075_sample_rate() {
+    let sensor = new_pct2075(&[
+        I2cTrans::write_read(ADDR, vec![Register::T_IDLE], vec![2]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0000]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0b0001_1001, 0]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0000]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0b0001_1010, 0]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+    ]);
+    let delay = CheckedDelay::new(&[
+        DelayTrans::blocking_delay_ms(228),
+        DelayTrans::blocking_delay_ms(772),
+        DelayTrans::blocking_delay_ms(228),
+    ]);
+    let mut sampler = sensor.with_duty_cycle(delay, 1000);
+    assert_eq!(25.0, sampler.sample().unwrap());
+    assert_eq!(26.0, sampler.sample().unwrap());
+    let (sensor, mut delay) = sampler.destroy();
+    delay.done();
+    destroy(sensor);
+}
+
+#[cfg(feature = "mcp980x")]
+#[test]
+fn duty_cycle_samples_back_to_back_for_short_periods() {
+    let sensor = new_mcp980x(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b1000_0001]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0b0001_1001, 0]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b1000_0001]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0b0001_1001, 0]),
+    ]);
+    let delay = CheckedDelay::new(&[
+        DelayTrans::blocking_delay_ms(75),
+        DelayTrans::blocking_delay_ms(0),
+        DelayTrans::blocking_delay_ms(75),
+    ]);
+    let mut sampler = sensor.with_duty_cycle(delay, 50);
+    assert_eq!(25.0, sampler.sample().unwrap());
+    assert_eq!(25.0, sampler.sample().unwrap());
+    let (sensor, mut delay) = sampler.destroy();
+    delay.done();
+    destroy(sensor);
+}
+
+#[test]
+fn cannot_read_stale_temperature_in_shutdown() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+        I2cTrans::transaction_start(ADDR),
+        I2cTrans::write(ADDR, vec![Register::TEMPERATURE]),
+        I2cTrans::read(ADDR, vec![0b0001_1001, 0]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION]),
+        I2cTrans::read(ADDR, vec![0b0000_0001]),
+        I2cTrans::transaction_end(ADDR),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0000]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0b0001_1001, 0]),
+    ]);
     sensor.disable().unwrap();
+    assert!(matches!(sensor.read_temperature(), Err(Error::Shutdown)));
+    assert!(matches!(
+        sensor.read_temperature_raw(),
+        Err(Error::Shutdown)
+    ));
+    assert!(sensor.read_measurement().unwrap().shutdown);
+    sensor.enable().unwrap();
+    assert_eq!(25.0, sensor.read_temperature().unwrap());
     destroy(sensor);
 }
 
 #[test]
-fn can_read_temperature() {
-    let mut sensor = new(&[I2cTrans::write_read(
+fn cannot_read_temperature_non_blocking_in_shutdown() {
+    let mut sensor = new(&[I2cTrans::write(
         ADDR,
-        vec![Register::TEMPERATURE],
-        vec![0b1110_0111, 0b1010_0101], // -24.5
+        vec![Register::CONFIGURATION, 0b0000_0001],
     )]);
-    let temp = sensor.read_temperature().unwrap();
-    assert!(-24.4 > temp);
-    assert!(-24.6 < temp);
+    sensor.disable().unwrap();
+    assert!(matches!(
+        sensor.try_read_temperature(),
+        Err(nb::Error::Other(Error::Shutdown))
+    ));
+    assert!(matches!(
+        sensor.try_read_temperature_raw(),
+        Err(nb::Error::Other(Error::Shutdown))
+    ));
     destroy(sensor);
 }
 
+#[cfg(feature = "mcp980x")]
 #[test]
-fn can_read_temperature_pct2075() {
-    let mut sensor = new_pct2075(&[I2cTrans::write_read(
-        ADDR,
-        vec![Register::TEMPERATURE],
-        vec![0b1110_0111, 0b1010_0101], // -24.375
-    )]);
-    let temp = sensor.read_temperature().unwrap();
-    assert!(-24.3 > temp);
-    assert!(-24.4 < temp);
+fn can_read_temperature_in_shutdown_after_one_shot() {
+    let mut sensor = new_mcp980x(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b1000_0001]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0b0001_1001, 0]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0000]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+    ]);
+    sensor.trigger_one_shot().unwrap();
+    assert_eq!(25.0, sensor.read_temperature().unwrap());
+    sensor.enable().unwrap();
+    sensor.disable().unwrap();
+    assert!(matches!(sensor.read_temperature(), Err(Error::Shutdown)));
+    destroy(sensor);
+}
+
+#[cfg(feature = "mcp980x")]
+#[test]
+fn cannot_read_one_shot_result_twice() {
+    let mut sensor = new_mcp980x(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b1000_0001]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0b0001_1001, 0]),
+    ]);
+    sensor.trigger_one_shot().unwrap();
+    assert_eq!(25.0, sensor.read_temperature().unwrap());
+    assert!(matches!(sensor.read_temperature(), Err(Error::Shutdown)));
+    assert!(matches!(
+        sensor.read_temperature_raw(),
+        Err(Error::Shutdown)
+    ));
+    destroy(sensor);
+}
+
+#[cfg(feature = "tmp75")]
+#[test]
+fn can_enable_and_wait() {
+    let mut sensor = new_tmp75(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0010_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0010_0000]),
+        I2cTrans::write_read(ADDR, vec![Register::TEMPERATURE], vec![0b0001_1001, 0]),
+    ]);
+    let mut delay = CheckedDelay::new(&[DelayTrans::blocking_delay_ms(75)]);
+    sensor.disable().unwrap();
+    sensor.set_resolution(Resolution::Bits10).unwrap();
+    sensor.enable_and_wait(&mut delay).unwrap();
+    assert_eq!(25.0, sensor.read_temperature().unwrap());
+    delay.done();
+    destroy(sensor);
+}
+
+#[cfg(feature = "pct2075")]
+#[test]
+fn can_set_power_profile_pct2075() {
+    let mut sensor = new_pct2075(&[
+        I2cTrans::write(ADDR, vec![Register::T_IDLE, 10]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0000]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+        I2cTrans::write(ADDR, vec![Register::T_IDLE, 1]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0000]),
+    ]);
+    sensor
+        .set_power_profile(PowerProfile::LowPower { period_ms: 1000 })
+        .unwrap();
+    sensor.set_power_profile(PowerProfile::Shutdown).unwrap();
+    assert_invalid_input_data_error(
+        sensor.set_power_profile(PowerProfile::LowPower { period_ms: 1050 }),
+    );
+    sensor.set_power_profile(PowerProfile::Normal).unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn can_set_power_mode() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0000]),
+    ]);
+    sensor.set_power_mode(PowerMode::Shutdown).unwrap();
+    sensor.set_power_mode(PowerMode::Continuous).unwrap();
+    assert_invalid_input_data_error(sensor.set_power_mode(PowerMode::OneShot));
+    destroy(sensor);
+}
+
+#[cfg(feature = "mcp980x")]
+#[test]
+fn can_set_one_shot_power_mode_mcp980x() {
+    let mut sensor = new_mcp980x(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b1000_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b1000_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0000]),
+    ]);
+    sensor.set_power_mode(PowerMode::OneShot).unwrap();
+    sensor.set_power_mode(PowerMode::OneShot).unwrap();
+    sensor.set_power_mode(PowerMode::Continuous).unwrap();
+    destroy(sensor);
+}
+
+#[cfg(feature = "mcp980x")]
+#[test]
+fn one_shot_trigger_is_not_verified() {
+    let mut sensor = new_mcp980x(&[
+        I2cTrans::transaction_start(ADDR),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 1]),
+        I2cTrans::read(ADDR, vec![1]),
+        I2cTrans::transaction_end(ADDR),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b1000_0001]),
+    ])
+    .with_verified_writes(true);
+    sensor.trigger_one_shot().unwrap();
     destroy(sensor);
 }
 
+#[cfg(feature = "pct2075")]
 #[test]
 fn can_read_sample_rate() {
     let mut sensor = new_pct2075(&[I2cTrans::write_read(
@@ -69,6 +2070,76 @@ fn can_read_sample_rate() {
     destroy(sensor);
 }
 
+#[cfg(feature = "pct2075")]
+#[test]
+fn can_convert_into_pct2075() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0100]),
+        I2cTrans::write(ADDR, vec![Register::T_IDLE, 0b0000_1111]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0001_0100]),
+        I2cTrans::write_read(
+            ADDR,
+            vec![Register::TEMPERATURE],
+            vec![0b1110_0111, 0b1010_0101], // -24.375
+        ),
+    ]);
+    sensor.set_os_polarity(OsPolarity::ActiveHigh).unwrap();
+    let mut sensor = sensor.into_pct2075();
+    sensor.set_sample_rate(1500).unwrap();
+    // keeps the cached configuration
+    sensor.set_fault_queue(FaultQueue::_4).unwrap();
+    assert_eq!(0.125, sensor.resolution());
+    let temp = sensor.read_temperature().unwrap();
+    assert!(-24.3 > temp);
+    assert!(-24.4 < temp);
+    destroy(sensor);
+}
+
+#[cfg(feature = "pct2075")]
+#[test]
+fn can_convert_into_lm75() {
+    let sensor = new_pct2075(&[I2cTrans::write_read(
+        ADDR,
+        vec![Register::TEMPERATURE],
+        vec![0b1110_0111, 0b1010_0101], // -24.5
+    )]);
+    let mut sensor = sensor.into_lm75();
+    assert_eq!(-24.5, sensor.read_temperature().unwrap());
+    destroy(sensor);
+}
+
+#[cfg(feature = "tmp1075")]
+#[test]
+fn can_convert_into_dynamic() {
+    let sensor = new(&[]).into_dynamic(Variant::Tmp1075);
+    assert_eq!(Variant::Tmp1075, sensor.variant());
+    assert_eq!(0.0625, sensor.resolution());
+    destroy(sensor);
+}
+
+#[cfg(feature = "pct2075")]
+#[test]
+fn can_configure_pct2075() {
+    let mut sensor = new_pct2075(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0000]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0001_0000]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0001_0100]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0001_0110]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0101_0000, 0b1000_0000]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b0100_1011, 0b0010_0000]),
+    ]);
+    sensor.disable().unwrap();
+    sensor.enable().unwrap();
+    sensor.set_fault_queue(FaultQueue::_4).unwrap();
+    sensor.set_os_polarity(OsPolarity::ActiveHigh).unwrap();
+    sensor.set_os_mode(OsMode::Interrupt).unwrap();
+    sensor.set_os_temperature(80.5).unwrap();
+    // 11-bit encoding
+    sensor.set_hysteresis_temperature(75.125).unwrap();
+    destroy(sensor);
+}
+
 macro_rules! set_config_test {
     ( $test_name:ident, $method:ident, $value:expr, $expected:expr ) => {
         #[test]
@@ -179,7 +2250,7 @@ macro_rules! invalid_temp_test {
         #[test]
         fn $test_name() {
             let mut sensor = new(&[]);
-            assert_invalid_input_data_error(sensor.$method($value));
+            assert_out_of_range_error(sensor.$method($value));
             destroy(sensor);
         }
     };
@@ -224,9 +2295,225 @@ invalid_temp_test!(
     125.5
 );
 
+set_temp_test!(
+    can_set_os_temp_raw,
+    set_os_temperature_raw,
+    0x19E0,
+    Register::T_OS,
+    0b0001_1001,
+    0b1000_0000
+);
+set_temp_test!(
+    can_set_hyst_temp_raw,
+    set_hysteresis_temperature_raw,
+    -0x80,
+    Register::T_HYST,
+    0b1111_1111,
+    0b1000_0000
+);
+
+invalid_temp_test!(
+    set_os_temperature_raw_too_low,
+    set_os_temperature_raw,
+    -55 * 256 - 1
+);
+invalid_temp_test!(
+    set_hyst_temperature_raw_too_high,
+    set_hysteresis_temperature_raw,
+    125 * 256 + 1
+);
+
+#[test]
+fn can_set_os_window() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b0010_1000, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0011_0010, 0b1000_0000]),
+    ]);
+    sensor.set_os_window(50.5, 40.0).unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn can_configure_alert() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b0010_1000, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0011_0010, 0b1000_0000]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0001_0110]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b0010_0011, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0010_1101, 0]),
+    ]);
+    let alert = AlertConfig {
+        os_temperature: 50.5,
+        hysteresis_temperature: 40.0,
+        fault_queue: FaultQueue::_4,
+        os_polarity: OsPolarity::ActiveHigh,
+        os_mode: OsMode::Interrupt,
+    };
+    assert_eq!(
+        (Celsius(50.5), Celsius(40.0)),
+        sensor.configure_alert(&alert).unwrap()
+    );
+    let alert = AlertConfig {
+        os_temperature: 45.0,
+        hysteresis_temperature: 35.0,
+        ..alert
+    };
+    sensor.configure_alert(&alert).unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn cannot_configure_alert_with_inverted_window() {
+    let mut sensor = new(&[]);
+    let alert = AlertConfig {
+        os_temperature: 40.0,
+        hysteresis_temperature: 50.0,
+        ..AlertConfig::default()
+    };
+    assert_invalid_input_data_error(sensor.configure_alert(&alert));
+    destroy(sensor);
+}
+
+#[test]
+fn can_set_temperatures_in_any_format() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0011_0010, 0b1000_0000]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b0010_1000, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0011_0010, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b1110_0111, 0b1000_0000]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0011_0010, 0b1000_0000]),
+    ]);
+    sensor.set_os_temperature(Millicelsius(50_700)).unwrap();
+    sensor
+        .set_hysteresis_temperature(RawTemperature(40 * 256 + 100))
+        .unwrap();
+    sensor.set_os_temperature(Celsius(50.25)).unwrap();
+    sensor
+        .set_os_window(Millicelsius(50_500), RawTemperature(-24 * 256 - 128))
+        .unwrap();
+    assert_out_of_range_error(sensor.set_os_temperature(Millicelsius(125_500)));
+    assert_out_of_range_error(sensor.set_os_temperature(RawTemperature(i16::MIN)));
+    destroy(sensor);
+}
+
+#[test]
+fn set_temperature_returns_value_written() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0000_0010, 0b1000_0000]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b1111_1101, 0b1000_0000]),
+    ]);
+    assert_eq!(Celsius(2.5), sensor.set_os_temperature(2.6).unwrap());
+    assert_eq!(
+        Celsius(-2.5),
+        sensor
+            .set_hysteresis_temperature(Millicelsius(-2_800))
+            .unwrap()
+    );
+    destroy(sensor);
+}
+
+#[test]
+fn can_round_temperatures_to_nearest() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0000_0011, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b1111_1101, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b0010_1000, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0011_0011, 0]),
+    ])
+    .with_rounding(Rounding::Nearest);
+    assert_eq!(Celsius(3.0), sensor.set_os_temperature(2.8).unwrap());
+    assert_eq!(
+        Celsius(-3.0),
+        sensor
+            .set_hysteresis_temperature(Millicelsius(-2_800))
+            .unwrap()
+    );
+    assert_eq!(
+        (Celsius(51.0), Celsius(40.0)),
+        sensor
+            .set_os_window(RawTemperature(51 * 256 - 10), 40.2)
+            .unwrap()
+    );
+    assert_out_of_range_error(sensor.set_os_temperature(125.2));
+    destroy(sensor);
+}
+
+#[test]
+fn can_set_raw_temperatures() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0101_0000, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b0100_1010, 0b1000_0000]),
+    ]);
+    assert_eq!(
+        80 * 256,
+        sensor.set_os_temperature_raw(80 * 256 + 10).unwrap()
+    );
+    assert_eq!(
+        74 * 256 + 128,
+        sensor
+            .set_hysteresis_temperature_raw(74 * 256 + 200)
+            .unwrap()
+    );
+    destroy(sensor);
+}
+
+#[test]
+fn cannot_round_temperatures_above_range() {
+    let mut sensor = new_custom_coarse(&[
+        I2cTrans::write(ADDR, vec![Register::T_OS, 124, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 124, 0]),
+    ]);
+    assert_eq!(Celsius(124.0), sensor.set_os_temperature(125.0).unwrap());
+    let mut sensor = sensor.with_rounding(Rounding::Nearest);
+    assert_out_of_range_error(sensor.set_os_temperature(125.0));
+    assert_out_of_range_error(sensor.set_os_temperature(Millicelsius(125_000)));
+    assert_out_of_range_error(sensor.set_os_temperature_raw(125 * 256));
+    assert_eq!(Celsius(124.0), sensor.set_os_temperature(124.9).unwrap());
+    destroy(sensor);
+}
+
+#[test]
+fn cannot_set_nan_or_infinite_temperatures() {
+    let mut sensor = new(&[]);
+    assert_invalid_input_data_error(sensor.set_os_temperature(f32::NAN));
+    assert_invalid_input_data_error(sensor.set_os_temperature(f32::INFINITY));
+    assert_invalid_input_data_error(sensor.set_hysteresis_temperature(f64::NEG_INFINITY));
+    assert_invalid_input_data_error(sensor.set_hysteresis_temperature(Celsius(f32::NAN)));
+    assert_invalid_input_data_error(sensor.set_os_temperature_f(f32::NAN));
+    assert_invalid_input_data_error(sensor.set_os_window(50.0, f32::NAN));
+    let settings = Settings {
+        os_temperature: f32::NAN,
+        ..Settings::default()
+    };
+    assert_invalid_input_data_error(sensor.apply_settings(&settings));
+    destroy(sensor);
+}
+
+#[test]
+fn cannot_set_inverted_os_window() {
+    let mut sensor = new(&[]);
+    assert_invalid_input_data_error(sensor.set_os_window(40.0, 50.0));
+    destroy(sensor);
+}
+
+#[test]
+fn cannot_set_os_window_equal_after_conversion() {
+    let mut sensor = new(&[]);
+    assert_invalid_input_data_error(sensor.set_os_window(50.2, 50.1));
+    destroy(sensor);
+}
+
+#[test]
+fn cannot_set_os_window_out_of_range() {
+    let mut sensor = new(&[]);
+    assert_out_of_range_error(sensor.set_os_window(130.0, 50.0));
+    destroy(sensor);
+}
+
 macro_rules! set_sample_rate_test {
     ( $test_name:ident, $method:ident, $value:expr, $register:expr,
       $period:expr) => {
+        #[cfg(feature = "pct2075")]
         #[test]
         fn $test_name() {
             let mut sensor = new_pct2075(&[I2cTrans::write(ADDR, vec![$register, $period])]);
@@ -260,6 +2547,7 @@ set_sample_rate_test!(
 
 macro_rules! invalid_sample_rate_test {
     ($test_name:ident, $method:ident, $value:expr) => {
+        #[cfg(feature = "pct2075")]
         #[test]
         fn $test_name() {
             let mut sensor = new_pct2075(&[]);
@@ -269,5 +2557,414 @@ macro_rules! invalid_sample_rate_test {
     };
 }
 
-invalid_sample_rate_test!(set_sample_rate_too_high, set_sample_rate, 4000);
+#[cfg(feature = "pct2075")]
+#[test]
+fn set_sample_rate_too_high() {
+    let mut sensor = new_pct2075(&[]);
+    assert_out_of_range_error(sensor.set_sample_rate(4000));
+    destroy(sensor)
+}
+
 invalid_sample_rate_test!(set_non_multiple_sample_rate, set_sample_rate, 1234);
+
+#[test]
+fn can_read_measurement() {
+    let mut sensor = new(&[
+        I2cTrans::transaction_start(ADDR),
+        I2cTrans::write(ADDR, vec![Register::TEMPERATURE]),
+        I2cTrans::read(ADDR, vec![0b0001_1001, 0]), // 25.0
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION]),
+        I2cTrans::read(ADDR, vec![0b0000_0000]),
+        I2cTrans::transaction_end(ADDR),
+    ]);
+    let measurement = sensor.read_measurement().unwrap();
+    assert_eq!(25.0, measurement.temperature);
+    assert!(!measurement.shutdown);
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_measurement_in_shutdown() {
+    let mut sensor = new(&[
+        I2cTrans::transaction_start(ADDR),
+        I2cTrans::write(ADDR, vec![Register::TEMPERATURE]),
+        I2cTrans::read(ADDR, vec![0b0001_1001, 0]), // 25.0
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION]),
+        I2cTrans::read(ADDR, vec![0b0000_0001]),
+        I2cTrans::transaction_end(ADDR),
+    ]);
+    let measurement = sensor.read_measurement().unwrap();
+    assert_eq!(25.0, measurement.temperature);
+    assert!(measurement.shutdown);
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_register() {
+    let mut sensor = new(&[I2cTrans::write_read(ADDR, vec![0x07], vec![0xA1])]);
+    let mut data = [0];
+    sensor.read_register(Reg::Other(0x07), &mut data).unwrap();
+    assert_eq!([0xA1], data);
+    destroy(sensor);
+}
+
+#[test]
+fn can_write_register() {
+    let mut sensor = new(&[I2cTrans::write(ADDR, vec![Register::T_OS, 0xAB, 0xCD])]);
+    sensor.write_register(Reg::TOs, &[0xAB, 0xCD]).unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn cannot_write_too_much_register_data() {
+    let mut sensor = new(&[]);
+    assert_invalid_input_data_error(sensor.write_register(Reg::TOs, &[0, 1, 2]));
+    destroy(sensor);
+}
+
+#[test]
+fn can_dump_registers() {
+    let mut sensor = new(&[
+        I2cTrans::transaction_start(ADDR),
+        I2cTrans::write(ADDR, vec![Register::TEMPERATURE]),
+        I2cTrans::read(ADDR, vec![0x19, 0x80]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION]),
+        I2cTrans::read(ADDR, vec![0x02]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST]),
+        I2cTrans::read(ADDR, vec![0x4B, 0x00]),
+        I2cTrans::write(ADDR, vec![Register::T_OS]),
+        I2cTrans::read(ADDR, vec![0x50, 0x00]),
+        I2cTrans::transaction_end(ADDR),
+    ]);
+    let dump = sensor.dump_registers().unwrap();
+    let expected = RegisterDump {
+        temperature: [0x19, 0x80],
+        configuration: 0x02,
+        t_hyst: [0x4B, 0x00],
+        t_os: [0x50, 0x00],
+        t_idle: None,
+    };
+    assert_eq!(expected, dump);
+    destroy(sensor);
+}
+
+#[cfg(feature = "pct2075")]
+#[test]
+fn can_dump_registers_pct2075() {
+    let mut sensor = new_pct2075(&[
+        I2cTrans::transaction_start(ADDR),
+        I2cTrans::write(ADDR, vec![Register::TEMPERATURE]),
+        I2cTrans::read(ADDR, vec![0x19, 0x80]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION]),
+        I2cTrans::read(ADDR, vec![0x02]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST]),
+        I2cTrans::read(ADDR, vec![0x4B, 0x00]),
+        I2cTrans::write(ADDR, vec![Register::T_OS]),
+        I2cTrans::read(ADDR, vec![0x50, 0x00]),
+        I2cTrans::write(ADDR, vec![Register::T_IDLE]),
+        I2cTrans::read(ADDR, vec![0x01]),
+        I2cTrans::transaction_end(ADDR),
+    ]);
+    let dump = sensor.dump_registers().unwrap();
+    let expected = RegisterDump {
+        temperature: [0x19, 0x80],
+        configuration: 0x02,
+        t_hyst: [0x4B, 0x00],
+        t_os: [0x50, 0x00],
+        t_idle: Some(0x01),
+    };
+    assert_eq!(expected, dump);
+    destroy(sensor);
+}
+
+#[test]
+fn can_apply_settings() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b0010_1000, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0011_0010, 0b1000_0000]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0001_0110]),
+    ]);
+    let settings = Settings {
+        fault_queue: FaultQueue::_4,
+        os_polarity: OsPolarity::ActiveHigh,
+        os_mode: OsMode::Interrupt,
+        os_temperature: 50.5,
+        hysteresis_temperature: 40.0,
+        ..Default::default()
+    };
+    sensor.apply_settings(&settings).unwrap();
+    destroy(sensor);
+}
+
+#[cfg(feature = "pct2075")]
+#[test]
+fn can_apply_settings_pct2075() {
+    let mut sensor = new_pct2075(&[
+        I2cTrans::write(ADDR, vec![Register::T_IDLE, 0b0000_1111]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b0100_1011, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0101_0000, 0]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+    ]);
+    let settings = Settings {
+        shutdown: true,
+        sample_rate: Some(1500),
+        ..Default::default()
+    };
+    sensor.apply_settings(&settings).unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn cannot_apply_settings_with_sample_rate_on_lm75() {
+    let mut sensor = new(&[]);
+    let settings = Settings {
+        sample_rate: Some(1500),
+        ..Default::default()
+    };
+    assert_invalid_input_data_error(sensor.apply_settings(&settings));
+    destroy(sensor);
+}
+
+#[cfg(feature = "pct2075")]
+#[test]
+fn can_apply_settings_dynamic_pct2075() {
+    let mut sensor = new_dynamic(
+        &[
+            I2cTrans::write(ADDR, vec![Register::T_IDLE, 0b0000_1111]),
+            I2cTrans::write(ADDR, vec![Register::T_HYST, 0b0100_1011, 0]),
+            I2cTrans::write(ADDR, vec![Register::T_OS, 0b0101_0000, 0]),
+            I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+        ],
+        Variant::Pct2075,
+    );
+    let settings = Settings {
+        shutdown: true,
+        sample_rate: Some(1500),
+        ..Default::default()
+    };
+    sensor.apply_settings(&settings).unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn cannot_apply_settings_with_sample_rate_on_dynamic_lm75() {
+    let mut sensor = new_dynamic(&[], Variant::Lm75);
+    let settings = Settings {
+        sample_rate: Some(1500),
+        ..Default::default()
+    };
+    assert_invalid_input_data_error(sensor.apply_settings(&settings));
+    destroy(sensor);
+}
+
+#[test]
+fn cannot_apply_settings_with_invalid_temperature() {
+    let mut sensor = new(&[]);
+    let settings = Settings {
+        hysteresis_temperature: -60.0,
+        ..Default::default()
+    };
+    assert_out_of_range_error(sensor.apply_settings(&settings));
+    destroy(sensor);
+}
+
+#[test]
+fn can_verify_writes() {
+    let mut sensor = new(&[
+        I2cTrans::transaction_start(ADDR),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 1]),
+        I2cTrans::read(ADDR, vec![1]),
+        I2cTrans::transaction_end(ADDR),
+        I2cTrans::transaction_start(ADDR),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0011_0010, 0]),
+        I2cTrans::read(ADDR, vec![0b0011_0010, 0]),
+        I2cTrans::transaction_end(ADDR),
+    ])
+    .with_verified_writes(true);
+    sensor.disable().unwrap();
+    sensor.set_os_temperature(50.0).unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn can_detect_failed_write_verification() {
+    let mut sensor = new(&[
+        I2cTrans::transaction_start(ADDR),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0011_0010, 0]),
+        I2cTrans::read(ADDR, vec![0b0011_0011, 0]),
+        I2cTrans::transaction_end(ADDR),
+    ])
+    .with_verified_writes(true);
+    match sensor.set_os_temperature(50.0) {
+        Err(Error::WriteVerificationFailed) => (),
+        _ => panic!("Did not return Error::WriteVerificationFailed."),
+    }
+    destroy(sensor);
+}
+
+#[test]
+fn can_read_temperature_without_repeated_start() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::TEMPERATURE]),
+        I2cTrans::read(ADDR, vec![0b0001_1001, 0b1000_0000]), // 25.5
+    ])
+    .with_repeated_start(false);
+    assert_eq!(25.5, sensor.read_temperature().unwrap());
+    destroy(sensor);
+}
+
+#[test]
+fn can_dump_registers_without_repeated_start() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::TEMPERATURE]),
+        I2cTrans::read(ADDR, vec![0x19, 0x80]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION]),
+        I2cTrans::read(ADDR, vec![0x02]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST]),
+        I2cTrans::read(ADDR, vec![0x4B, 0x00]),
+        I2cTrans::write(ADDR, vec![Register::T_OS]),
+        I2cTrans::read(ADDR, vec![0x50, 0x00]),
+    ])
+    .with_repeated_start(false);
+    let dump = sensor.dump_registers().unwrap();
+    assert_eq!(0x02, dump.configuration);
+    assert_eq!([0x50, 0x00], dump.t_os);
+    destroy(sensor);
+}
+
+#[test]
+fn can_save_and_restore_state() {
+    let mut sensor = new(&[
+        I2cTrans::transaction_start(ADDR),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION]),
+        I2cTrans::read(ADDR, vec![0b0001_0110]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST]),
+        I2cTrans::read(ADDR, vec![0x4B, 0x80]),
+        I2cTrans::write(ADDR, vec![Register::T_OS]),
+        I2cTrans::read(ADDR, vec![0x50, 0x00]),
+        I2cTrans::transaction_end(ADDR),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0x4B, 0x80]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0x50, 0x00]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0001_0110]),
+        // cached configuration is updated
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0001_0111]),
+    ]);
+    let state = sensor.save_state().unwrap();
+    assert_eq!(None, state.t_idle);
+    sensor.restore_state(&state).unwrap();
+    sensor.disable().unwrap();
+    destroy(sensor);
+}
+
+#[cfg(feature = "pct2075")]
+#[test]
+fn can_save_and_restore_state_pct2075() {
+    let mut sensor = new_pct2075(&[
+        I2cTrans::transaction_start(ADDR),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION]),
+        I2cTrans::read(ADDR, vec![0b0000_0001]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST]),
+        I2cTrans::read(ADDR, vec![0x4B, 0x80]),
+        I2cTrans::write(ADDR, vec![Register::T_OS]),
+        I2cTrans::read(ADDR, vec![0x50, 0x00]),
+        I2cTrans::write(ADDR, vec![Register::T_IDLE]),
+        I2cTrans::read(ADDR, vec![0x0F]),
+        I2cTrans::transaction_end(ADDR),
+        I2cTrans::write(ADDR, vec![Register::T_IDLE, 0x0F]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0x4B, 0x80]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0x50, 0x00]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+    ]);
+    let state = sensor.save_state().unwrap();
+    assert_eq!(Some(0x0F), state.t_idle);
+    sensor.restore_state(&state).unwrap();
+    destroy(sensor);
+}
+
+#[test]
+fn can_suspend_and_resume() {
+    let mut sensor = new(&[
+        I2cTrans::transaction_start(ADDR),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION]),
+        I2cTrans::read(ADDR, vec![0b0001_0110]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST]),
+        I2cTrans::read(ADDR, vec![0x4B, 0x80]),
+        I2cTrans::write(ADDR, vec![Register::T_OS]),
+        I2cTrans::read(ADDR, vec![0x50, 0x00]),
+        I2cTrans::transaction_end(ADDR),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0001_0111]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0x4B, 0x80]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0x50, 0x00]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0001_0110]),
+    ]);
+    let mut delay = CheckedDelay::new(&[DelayTrans::blocking_delay_ms(300)]);
+    let state = sensor.suspend().unwrap();
+    sensor.resume(&state, &mut delay).unwrap();
+    delay.done();
+    destroy(sensor);
+}
+
+#[test]
+fn does_not_wait_when_resuming_into_shutdown() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0x4B, 0x00]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0x50, 0x00]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+    ]);
+    let mut delay = CheckedDelay::new(&[]);
+    let state = DeviceState::from_bytes(&[0b0000_0001, 0x4B, 0x00, 0x50, 0x00, 0, 0]);
+    sensor.resume(&state, &mut delay).unwrap();
+    delay.done();
+    destroy(sensor);
+}
+
+#[test]
+fn cannot_restore_state_with_sample_rate_on_lm75() {
+    let mut sensor = new(&[]);
+    let state = DeviceState {
+        t_idle: Some(0x0F),
+        ..Default::default()
+    };
+    assert_invalid_input_data_error(sensor.restore_state(&state));
+    destroy(sensor);
+}
+
+#[test]
+fn can_reset_to_defaults() {
+    let mut sensor = new(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0000_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b0100_1011, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0101_0000, 0]),
+    ]);
+    sensor.disable().unwrap();
+    sensor.reset_to_defaults().unwrap();
+    destroy(sensor);
+}
+
+#[cfg(feature = "pct2075")]
+#[test]
+fn can_reset_to_defaults_pct2075() {
+    let mut sensor = new_pct2075(&[
+        I2cTrans::write(ADDR, vec![Register::T_IDLE, 0b0000_0001]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b0100_1011, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0101_0000, 0]),
+    ]);
+    sensor.reset_to_defaults().unwrap();
+    destroy(sensor);
+}
+
+#[cfg(feature = "max3172x")]
+#[test]
+fn can_reset_to_defaults_in_extended_format_max3172x() {
+    let mut sensor = new_max3172x(&[
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0b0010_0000]),
+        I2cTrans::write(ADDR, vec![Register::CONFIGURATION, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_HYST, 0b0100_1011, 0]),
+        I2cTrans::write(ADDR, vec![Register::T_OS, 0b0101_0000, 0]),
+    ]);
+    sensor.enable_extended_format().unwrap();
+    sensor.reset_to_defaults().unwrap();
+    destroy(sensor);
+}